@@ -4,7 +4,10 @@ use std::{borrow::Cow, sync::LazyLock};
 pub mod features;
 pub mod region;
 
-use crate::utils::{BinMap, PooledStr};
+use crate::{
+    utils::{BinMap, PooledStr},
+    BROWSER_ALIASES, BROWSER_DISPLAY_NAMES, MOBILE_TO_DESKTOP,
+};
 
 pub const ANDROID_EVERGREEN_FIRST: f32 = 37.0;
 pub const OP_MOB_BLINK_FIRST: u32 = 14;
@@ -13,16 +16,75 @@ pub const OP_MOB_BLINK_FIRST: u32 = 14;
 pub struct BrowserStat(u32, u32);
 
 #[derive(Clone, Debug)]
+/// Detail of a browser version.
 pub struct VersionDetail {
     version: PooledStr,
+    /// Release date as Unix timestamp in seconds, or `0` if unreleased.
     pub release_date: i64,
     // Use bool instead of Option to use pad space
+    /// Whether this version has been released.
     pub released: bool,
+    /// Rendering engine of this version, or `None` if it's unknown.
+    pub engine: Option<Engine>,
+    engine_version: PooledStr,
+    /// Global usage in percentage.
     pub global_usage: f32,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Browser rendering engine.
+pub enum Engine {
+    /// Blink, used by Chromium-based browsers.
+    Blink,
+    /// EdgeHTML, used by legacy Edge.
+    EdgeHtml,
+    /// Gecko, used by Firefox.
+    Gecko,
+    /// Presto, used by legacy Opera.
+    Presto,
+    /// Trident, used by Internet Explorer.
+    Trident,
+    /// WebKit, used by Safari.
+    WebKit,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Whether a browser is for desktop or mobile.
+pub enum BrowserType {
+    /// Desktop browser.
+    Desktop,
+    /// Mobile browser.
+    Mobile,
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Metadata of a browser.
+pub struct BrowserMeta {
+    /// Display name, such as `Chrome for Android`.
+    pub display_name: &'static str,
+    /// Long name, such as `Internet Explorer`.
+    pub long_name: &'static str,
+    /// Abbreviation, such as `Chr/And.`.
+    pub abbr: &'static str,
+    /// Whether it's for desktop or mobile.
+    pub browser_type: BrowserType,
+}
+
 include!("generated/caniuse-browsers.rs");
 
+// ```rust
+// static BROWSER_META: &[(&str, BrowserMeta)]; // browser name and metadata, sorted by name
+// ```
+include!("generated/caniuse-browser-meta.rs");
+
+/// Prefixes which differ from the default prefix of a browser, by version.
+type PrefixExceptions = &'static [(&'static str, &'static str)];
+
+// ```rust
+// static BROWSER_PREFIXES: &[(&str, (&str, PrefixExceptions))]; // browser name, prefix and exceptions
+// ```
+include!("generated/caniuse-browser-prefixes.rs");
+
 static CANIUSE_BROWSERS: BinMap<PooledStr, BrowserStat> = BinMap(BROWSERS_STATS);
 
 static CANIUSE_GLOBAL_USAGE: &[(PooledStr, PooledStr, f32)] =
@@ -95,6 +157,9 @@ static ANDROID_TO_DESKTOP: LazyLock<Vec<VersionDetail>> = LazyLock::new(|| {
         .collect()
 });
 
+/// Get the canonical name and version list of a browser by name or alias.
+///
+/// With `mobile_to_desktop`, mobile browsers fall back to their desktop version list.
 pub fn get_browser_stat(
     name: &str,
     mobile_to_desktop: bool,
@@ -107,19 +172,16 @@ pub fn get_browser_stat(
     let name = get_browser_alias(&name);
 
     if mobile_to_desktop {
-        if let Some(desktop_name) = to_desktop_name(name) {
-            match name {
+        if let Some((&mobile_name, &desktop_name)) = BinMap(MOBILE_TO_DESKTOP).get_key_value(name) {
+            match mobile_name {
                 "android" => Some(("android", &*ANDROID_TO_DESKTOP)),
                 "op_mob" => {
                     let stat = CANIUSE_BROWSERS.get("opera").unwrap();
                     Some(("op_mob", stat.version_list()))
                 }
-                _ => CANIUSE_BROWSERS.get(desktop_name).map(|stat| {
-                    (
-                        get_mobile_by_desktop_name(desktop_name),
-                        stat.version_list(),
-                    )
-                }),
+                _ => CANIUSE_BROWSERS
+                    .get(desktop_name)
+                    .map(|stat| (mobile_name, stat.version_list())),
             }
         } else {
             CANIUSE_BROWSERS
@@ -133,6 +195,7 @@ pub fn get_browser_stat(
     }
 }
 
+/// Iterate over all browsers with their version lists.
 pub fn iter_browser_stat(
     mobile_to_desktop: bool,
 ) -> impl Iterator<Item = (&'static str, &'static [VersionDetail])> {
@@ -148,16 +211,14 @@ pub fn iter_browser_stat(
                 let stat = CANIUSE_BROWSERS.get("opera").unwrap();
                 Some(("op_mob", stat.version_list()))
             }
-            (true, Some(desktop_name), _) => CANIUSE_BROWSERS.get(desktop_name).map(|stat| {
-                (
-                    get_mobile_by_desktop_name(desktop_name),
-                    stat.version_list(),
-                )
-            }),
+            (true, Some(desktop_name), _) => CANIUSE_BROWSERS
+                .get(desktop_name)
+                .map(|stat| (name.as_str(), stat.version_list())),
         }
     })
 }
 
+/// Iterate over browser versions and global usages, sorted by usage in descending order.
 pub fn iter_global_usage() -> impl ExactSizeIterator<Item = (&'static str, &'static str, f32)> {
     CANIUSE_GLOBAL_USAGE
         .iter()
@@ -165,46 +226,39 @@ pub fn iter_global_usage() -> impl ExactSizeIterator<Item = (&'static str, &'sta
         .map(|(name, version, usage)| (name.as_str(), version.as_str(), usage))
 }
 
+/// Get the version range (like `4.4.3-4.4.4`) which the given version belongs to.
 pub fn get_browser_version_alias(name: &str, version: &str) -> Option<&'static str> {
     BROWSER_VERSION_ALIASES.get(name)?.get(version).copied()
 }
 
+/// Resolve an alias like `fx` or a display name like `samsung internet` to the agent name.
 fn get_browser_alias(name: &str) -> &str {
-    match name {
-        "fx" | "ff" => "firefox",
-        "ios" => "ios_saf",
-        "explorer" => "ie",
-        "blackberry" => "bb",
-        "explorermobile" => "ie_mob",
-        "operamini" => "op_mini",
-        "operamobile" => "op_mob",
-        "chromeandroid" => "and_chr",
-        "firefoxandroid" => "and_ff",
-        "ucandroid" => "and_uc",
-        "qqandroid" => "and_qq",
-        _ => name,
-    }
+    BinMap(BROWSER_ALIASES)
+        .get(name)
+        .copied()
+        .or_else(|| BinMap(BROWSER_DISPLAY_NAMES).get(name).copied())
+        .unwrap_or(name)
 }
 
+/// Get the desktop browser name of a mobile browser.
 pub fn to_desktop_name(name: &str) -> Option<&'static str> {
-    match name {
-        "and_chr" | "android" => Some("chrome"),
-        "and_ff" => Some("firefox"),
-        "ie_mob" => Some("ie"),
-        _ => None,
-    }
+    BinMap(MOBILE_TO_DESKTOP).get(name).copied()
 }
 
-fn get_mobile_by_desktop_name(name: &str) -> &'static str {
-    match name {
-        "chrome" => "and_chr", // "android" has been handled as a special case
-        "firefox" => "and_ff",
-        "ie" => "ie_mob",
-        "opera" => "op_mob",
-        _ => unreachable!(),
-    }
+/// Get metadata of the given browser. Aliases aren't resolved.
+pub fn get_browser_meta(name: &str) -> Option<BrowserMeta> {
+    BinMap(BROWSER_META).get(name).copied()
 }
 
+/// Get the vendor prefix (like `webkit` and `moz`) of the given browser version,
+/// considering prefix exceptions such as `o` for Opera before 15.
+pub fn get_browser_prefix(name: &str, version: &str) -> Option<&'static str> {
+    let &(prefix, exceptions) = BinMap(BROWSER_PREFIXES).get(name)?;
+    Some(BinMap(exceptions).get(version).copied().unwrap_or(prefix))
+}
+
+/// Normalize the given version to the one in the version list,
+/// resolving version aliases.
 pub fn normalize_version<'a>(
     name: &'a str,
     version_list: &'static [VersionDetail],
@@ -229,7 +283,14 @@ impl BrowserStat {
 }
 
 impl VersionDetail {
+    /// Return version string.
     pub fn version(&self) -> &'static str {
         self.version.as_str()
     }
+
+    /// Return version of the rendering engine, such as `115` for Samsung Internet 23,
+    /// or `None` if it's unknown.
+    pub fn engine_version(&self) -> Option<&'static str> {
+        Some(self.engine_version.as_str()).filter(|version| !version.is_empty())
+    }
 }
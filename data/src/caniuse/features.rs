@@ -4,27 +4,101 @@ use crate::{
     utils::{BinMap, PairU32, U32},
 };
 
+/// Bits of support flags.
+pub mod flags {
+    /// Supported (`y`).
+    pub const Y: u8 = 1;
+    /// Partially supported (`a`).
+    pub const A: u8 = 1 << 1;
+    /// Not supported (`n`).
+    pub const N: u8 = 1 << 2;
+    /// Requires prefix (`x`).
+    pub const X: u8 = 1 << 3;
+    /// Disabled by default (`d`).
+    pub const D: u8 = 1 << 4;
+    /// Supported with polyfill (`p`).
+    pub const P: u8 = 1 << 5;
+    /// Support unknown (`u`).
+    pub const U: u8 = 1 << 6;
+}
+
 #[derive(Clone, Copy)]
 pub struct Feature(u32, u32);
 
 #[derive(Clone, Copy)]
 pub struct VersionList(PairU32);
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Numbers of notes referenced by `#n` in support flags.
+pub struct Notes(&'static [U32]);
+
+#[derive(Clone, Copy, Debug)]
+/// Metadata of a feature.
+pub struct FeatureMeta {
+    /// Human-readable title, such as `CSS Grid Layout (level 1)`.
+    pub title: &'static str,
+    /// Description of the feature.
+    pub description: &'static str,
+    /// URL of the specification.
+    pub spec: &'static str,
+    /// Status of the specification, such as `ls`, `cr` and `wd`.
+    pub status: &'static str,
+    /// Categories, such as `CSS`, `JS API` and `HTML5`.
+    pub categories: &'static [&'static str],
+    /// Comma-separated keywords for searching.
+    pub keywords: &'static str,
+}
+
 // ```rust
 // static FEATURES: &[(PooledStr, Feature)]; // feature name and browsers list
 //
 // static FEATURES_STAT_VERSION_STORE: &[U32]; // version string
 // static FEATURES_STAT_VERSION_INDEX: &[PairU32]; // version range
 //
+// static FEATURES_STAT_NOTES_INDEX: &[U32]; // sorted index of stat entries which have notes
+// static FEATURES_STAT_NOTES_RANGE: &[PairU32]; // note numbers range of each entry above
+// static FEATURES_STAT_NOTES: &[U32]; // note numbers
 // static FEATURES_STAT_FLAGS: &[u8]; // support flag
 // static FEATURES_STAT_BROWSERS: &[u8]; // browser name id
 // ```
 include!("../generated/caniuse-feature-matching.rs");
 
+// ```rust
+// static FEATURES_META: &[FeatureMeta]; // metadata, in the same order as `FEATURES`
+// ```
+include!("../generated/caniuse-feature-meta.rs");
+
 pub fn get_feature_stat(name: &str) -> Option<Feature> {
     BinMap(FEATURES).get(name).copied()
 }
 
+/// Iterate over all features, sorted by name.
+pub fn iter_features() -> impl ExactSizeIterator<Item = (&'static str, Feature)> {
+    FEATURES
+        .iter()
+        .map(|(name, feature)| (name.as_str(), *feature))
+}
+
+/// Get the index of a feature in [`iter_features`].
+pub fn get_feature_index(name: &str) -> Option<usize> {
+    FEATURES
+        .binary_search_by(|(k, _)| k.as_str().cmp(name))
+        .ok()
+}
+
+/// Get metadata of a feature, along with its name.
+pub fn get_feature_meta(name: &str) -> Option<(&'static str, &'static FeatureMeta)> {
+    get_feature_index(name).map(|index| (FEATURES[index].0.as_str(), &FEATURES_META[index]))
+}
+
+/// Iterate over all features with their metadata, sorted by name.
+pub fn iter_feature_meta() -> impl ExactSizeIterator<Item = (&'static str, &'static FeatureMeta)> {
+    FEATURES
+        .iter()
+        .zip(FEATURES_META)
+        .map(|((name, _), meta)| (name.as_str(), meta))
+}
+
 impl Feature {
     pub fn get(&self, browser: &str) -> Option<VersionList> {
         let range = (self.0 as usize)..(self.1 as usize);
@@ -42,14 +116,52 @@ impl Feature {
             .zip(&FEATURES_STAT_VERSION_INDEX[range])
             .map(|(&name, &list)| (decode_browser_name(name), VersionList(list)))
     }
+
+    /// Get metadata of this feature.
+    pub fn meta(&self) -> &'static FeatureMeta {
+        // stats of features are stored in the same order as features
+        let index = FEATURES.partition_point(|(_, feature)| feature.0 < self.0);
+        &FEATURES_META[index]
+    }
+}
+
+impl Notes {
+    /// Iterate over note numbers in ascending order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = u32> {
+        self.0.iter().map(|n| n.get())
+    }
+
+    /// Return `true` if there are no notes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 impl VersionList {
+    /// Get support flags of the given version. See [`flags`] for meaning of each bit.
     pub fn get(&self, version: &str) -> Option<u8> {
         let range = (self.0.0.get() as usize)..(self.0.1.get() as usize);
-        let index = FEATURES_STAT_VERSION_STORE[range.clone()]
-            .binary_search_by_key(&version, |s| PooledStr(s.get()).as_str())
-            .ok()?;
+        let index = self.index(version)?;
         Some(FEATURES_STAT_FLAGS[range][index])
     }
+
+    /// Get note numbers of the given version.
+    pub fn get_notes(&self, version: &str) -> Option<Notes> {
+        let index = self.0.0.get() + u32::try_from(self.index(version)?).ok()?;
+        let notes = FEATURES_STAT_NOTES_INDEX
+            .binary_search_by_key(&index, |i| i.get())
+            .map(|i| {
+                let PairU32(start, end) = FEATURES_STAT_NOTES_RANGE[i];
+                &FEATURES_STAT_NOTES[(start.get() as usize)..(end.get() as usize)]
+            })
+            .unwrap_or_default();
+        Some(Notes(notes))
+    }
+
+    fn index(&self, version: &str) -> Option<usize> {
+        let range = (self.0.0.get() as usize)..(self.0.1.get() as usize);
+        FEATURES_STAT_VERSION_STORE[range]
+            .binary_search_by_key(&version, |s| PooledStr(s.get()).as_str())
+            .ok()
+    }
 }
@@ -2,6 +2,7 @@ use std::ops::Range;
 
 include!("generated/electron-to-chromium.rs");
 
+/// Iterate over Electron versions with their Chromium versions.
 pub fn versions() -> impl ExactSizeIterator<Item = (f32, &'static str)> + DoubleEndedIterator {
     ELECTRON_VERSIONS
         .iter()
@@ -9,6 +10,7 @@ pub fn versions() -> impl ExactSizeIterator<Item = (f32, &'static str)> + Double
         .zip(CHROMIUM_VERSIONS.iter().copied())
 }
 
+/// Get the Chromium version of the given Electron version.
 pub fn get(electron_version: f32) -> Option<&'static str> {
     let index = ELECTRON_VERSIONS
         .binary_search_by(|probe| probe.total_cmp(&electron_version))
@@ -16,6 +18,39 @@ pub fn get(electron_version: f32) -> Option<&'static str> {
     CHROMIUM_VERSIONS.get(index).copied()
 }
 
+/// Iterate over patch-level Electron versions with their full Chromium versions,
+/// from oldest to newest. Prereleases are not included.
+pub fn full_versions(
+) -> impl ExactSizeIterator<Item = (&'static str, &'static str)> + DoubleEndedIterator {
+    ELECTRON_FULL_VERSIONS.iter().copied()
+}
+
+/// Get the full Chromium version of the given patch-level Electron version, such as `28.2.3`.
+pub fn get_full(electron_version: &str) -> Option<&'static str> {
+    ELECTRON_FULL_VERSIONS
+        .iter()
+        .find(|(version, _)| *version == electron_version)
+        .map(|(_, chromium_version)| *chromium_version)
+}
+
+/// Get the original version string of the given Electron version, such as `0.20` for `0.2`.
+pub fn version_name(electron_version: f32) -> Option<&'static str> {
+    let index = ELECTRON_VERSIONS
+        .binary_search_by(|probe| probe.total_cmp(&electron_version))
+        .ok()?;
+    ELECTRON_VERSION_NAMES.get(index).copied()
+}
+
+/// Iterate over Electron versions which ship the given Chromium version, from oldest to newest.
+pub fn from_chromium(chromium_version: &str) -> impl Iterator<Item = f32> + '_ {
+    versions()
+        .filter(move |(_, chromium)| *chromium == chromium_version)
+        .map(|(electron, _)| electron)
+}
+
+/// Get Chromium versions of the given inclusive Electron version range.
+///
+/// The bound which is not a known Electron version will be returned as error.
 pub fn bounded_range(range: Range<f32>) -> Result<&'static [&'static str], f32> {
     let start = ELECTRON_VERSIONS
         .binary_search_by(|probe| probe.total_cmp(&range.start))
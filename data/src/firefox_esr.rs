@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+
+include!("generated/firefox-esr.rs");
+
+#[derive(Clone, Copy, Debug)]
+/// A Firefox Extended Support Release.
+pub struct EsrRelease {
+    /// Major version, such as `115`.
+    pub version: &'static str,
+    /// Date of the initial release.
+    pub start: NaiveDate,
+    /// End-of-life date, or `None` if it's still maintained.
+    pub end: Option<NaiveDate>,
+}
+
+/// List all Firefox ESR releases, from oldest to newest.
+pub fn releases() -> &'static [EsrRelease] {
+    FIREFOX_ESR_RELEASES
+}
+
+/// Iterate over Firefox ESR releases which are supported at the given date.
+pub fn supported(now: NaiveDate) -> impl Iterator<Item = &'static EsrRelease> {
+    FIREFOX_ESR_RELEASES
+        .iter()
+        .filter(move |release| release.start <= now && release.end.is_none_or(|end| now < end))
+}
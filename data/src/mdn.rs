@@ -0,0 +1,42 @@
+//! Support data from MDN browser-compat-data.
+
+use crate::utils::BinMap;
+
+#[derive(Clone, Copy, Debug)]
+/// A support statement of a browser.
+pub struct SupportStatement {
+    /// Version which added support, or `0` if it's supported since an unknown version.
+    pub version_added: &'static str,
+    /// Version which removed support, or empty if it's still supported.
+    pub version_removed: &'static str,
+    /// Support flags. See [`crate::caniuse::features::flags`] for meaning of each bit.
+    pub flags: u8,
+}
+
+/// Support statements by caniuse browser name.
+type BrowserSupport = &'static [(&'static str, &'static [SupportStatement])];
+
+#[derive(Clone, Copy)]
+pub struct MdnFeature(BrowserSupport);
+
+// ```rust
+// static MDN_FEATURES: &[(&str, BrowserSupport)]; // feature path and its support, sorted by path
+// ```
+include!("generated/mdn-features.rs");
+
+/// Get support data of a feature by its path, such as `api.fetch`.
+pub fn get_mdn_feature(path: &str) -> Option<MdnFeature> {
+    BinMap(MDN_FEATURES).get(path).copied().map(MdnFeature)
+}
+
+impl MdnFeature {
+    /// Get support statements of the given browser.
+    pub fn get(&self, browser: &str) -> Option<&'static [SupportStatement]> {
+        BinMap(self.0).get(browser).copied()
+    }
+
+    /// Iterate over browsers with their support statements.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &'static [SupportStatement])> {
+        self.0.iter().copied()
+    }
+}
@@ -3,16 +3,58 @@ use chrono::NaiveDate;
 include!("generated/node-versions.rs");
 include!("generated/node-release-schedule.rs");
 
+/// List all Node.js versions, from oldest to newest.
 pub fn versions() -> &'static [&'static str] {
     NODE_VERSIONS
 }
 
+/// Get the V8 version of the given Node.js version, such as `10.2.154.26` for `18.17.0`.
+pub fn v8_version(node_version: &str) -> Option<&'static str> {
+    NODE_VERSIONS
+        .iter()
+        .position(|version| *version == node_version)
+        .and_then(|index| NODE_V8_VERSIONS.get(index).copied())
+}
+
+/// Get the major version of Chromium which ships the same V8 version
+/// as the given Node.js version, such as `102` for `18.0.0`.
+///
+/// Since V8 4.1 (Chromium 41), V8 version `x.y` is shipped with Chromium `xy`.
+/// Older versions are unknown.
+pub fn chromium_version(node_version: &str) -> Option<u32> {
+    let mut segments = v8_version(node_version)?.split('.');
+    let major = segments.next()?.parse::<u32>().ok()?;
+    let minor = segments.next()?.parse::<u32>().ok()?;
+    let chromium_version = major * 10 + minor;
+    (minor < 10 && chromium_version >= 41).then_some(chromium_version)
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Release schedule of a Node.js major version.
+pub struct ReleaseSchedule {
+    /// Major version, such as `18` or `0.10`.
+    pub version: &'static str,
+    /// Date of the initial release.
+    pub start: NaiveDate,
+    /// Date of entering active LTS, or `None` if it never becomes LTS.
+    pub lts: Option<NaiveDate>,
+    /// Date of entering maintenance.
+    pub maintenance: Option<NaiveDate>,
+    /// End-of-life date.
+    pub end: NaiveDate,
+    /// LTS codename, such as `Hydrogen`.
+    pub codename: Option<&'static str>,
+}
+
+/// List release schedules of all Node.js major versions, from oldest to newest.
+pub fn releases() -> &'static [ReleaseSchedule] {
+    NODE_RELEASE_SCHEDULE
+}
+
+/// Iterate over Node.js major versions which are maintained at the given date.
 pub fn release_schedule(now: NaiveDate) -> impl Iterator<Item = &'static str> {
-    let end = NODE_RELEASE_SCHEDULE.partition_point(|(_, end)| end <= &now);
     NODE_RELEASE_SCHEDULE
         .iter()
-        .enumerate()
-        .skip(end)
-        .filter(move |(_, (start, _))| start < &now)
-        .map(|(idx, _)| NODE_RELEASE_VERSIONS[idx])
+        .filter(move |release| release.start < now && now < release.end)
+        .map(|release| release.version)
 }
@@ -34,7 +34,7 @@ impl<K, V> BinMap<'_, K, V> {
 #[repr(C)]
 pub(super) struct PairU32(pub U32, pub U32);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub(super) struct U32(u32);
 
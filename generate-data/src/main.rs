@@ -4,38 +4,277 @@ use quote::quote;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fs,
     io::{self, Write},
 };
 
 const OUT_DIR: &str = "data/src/generated";
 
-fn encode_browser_name(name: &str) -> u8 {
-    match name {
-        "ie" => 1,
-        "edge" => 2,
-        "firefox" => 3,
-        "chrome" => 4,
-        "safari" => 5,
-        "opera" => 6,
-        "ios_saf" => 7,
-        "op_mini" => 8,
-        "android" => 9,
-        "bb" => 10,
-        "op_mob" => 11,
-        "and_chr" => 12,
-        "and_ff" => 13,
-        "ie_mob" => 14,
-        "and_uc" => 15,
-        "samsung" => 16,
-        "and_qq" => 17,
-        "baidu" => 18,
-        "kaios" => 19,
-        _ => unreachable!("unknown browser name"),
-    }
+/// Bits of support flags, which must be kept in sync with `browserslist_data::caniuse::features::flags`.
+mod flags {
+    pub const Y: u8 = 1;
+    pub const A: u8 = 1 << 1;
+    pub const N: u8 = 1 << 2;
+    pub const X: u8 = 1 << 3;
+    pub const D: u8 = 1 << 4;
+    pub const P: u8 = 1 << 5;
+    pub const U: u8 = 1 << 6;
 }
 
+/// Aliases accepted in queries, mapped to caniuse agent names.
+const BROWSER_ALIASES: &[(&str, &str)] = &[
+    ("fx", "firefox"),
+    ("ff", "firefox"),
+    ("ios", "ios_saf"),
+    ("explorer", "ie"),
+    ("blackberry", "bb"),
+    ("explorermobile", "ie_mob"),
+    ("operamini", "op_mini"),
+    ("operamobile", "op_mob"),
+    ("chromeandroid", "and_chr"),
+    ("firefoxandroid", "and_ff"),
+    ("ucandroid", "and_uc"),
+    ("qqandroid", "and_qq"),
+];
+
+/// Browsers in MDN browser-compat-data, mapped to caniuse agent names.
+const MDN_BROWSERS: &[(&str, &str)] = &[
+    ("chrome", "chrome"),
+    ("chrome_android", "and_chr"),
+    ("edge", "edge"),
+    ("firefox", "firefox"),
+    ("firefox_android", "and_ff"),
+    ("ie", "ie"),
+    ("opera", "opera"),
+    ("opera_android", "op_mob"),
+    ("safari", "safari"),
+    ("safari_ios", "ios_saf"),
+    ("samsunginternet_android", "samsung"),
+    ("webview_android", "android"),
+];
+
+/// Rendering engine of each browser.
+const BROWSER_ENGINES: &[(&str, &str)] = &[
+    ("and_chr", "Blink"),
+    ("and_ff", "Gecko"),
+    ("and_qq", "Blink"),
+    ("and_uc", "Blink"),
+    ("android", "Blink"),
+    ("baidu", "Blink"),
+    ("bb", "WebKit"),
+    ("chrome", "Blink"),
+    ("edge", "Blink"),
+    ("firefox", "Gecko"),
+    ("ie", "Trident"),
+    ("ie_mob", "Trident"),
+    ("ios_saf", "WebKit"),
+    ("kaios", "Gecko"),
+    ("op_mini", "Presto"),
+    ("op_mob", "Blink"),
+    ("opera", "Blink"),
+    ("safari", "WebKit"),
+    ("samsung", "Blink"),
+];
+
+/// Legacy rendering engine of browsers, with the last version using it.
+const LEGACY_ENGINES: &[(&str, &str, &str)] = &[
+    ("android", "WebKit", "4.4.4"),
+    ("chrome", "WebKit", "27"),
+    ("edge", "EdgeHtml", "18"),
+    ("op_mob", "Presto", "12.1"),
+    ("opera", "Presto", "12.1"),
+];
+
+/// Chromium versions of Opera, by Opera major version.
+///
+/// Opera doesn't follow Chromium with a fixed offset,
+/// for example, Opera 69 skipped Chromium 82 and Opera 115 skipped Chromium 129.
+const OPERA_CHROMIUM_VERSIONS: &[(&str, &str)] = &[
+    ("15", "28"),
+    ("16", "29"),
+    ("17", "30"),
+    ("18", "31"),
+    ("19", "32"),
+    ("20", "33"),
+    ("21", "34"),
+    ("22", "35"),
+    ("23", "36"),
+    ("24", "37"),
+    ("25", "38"),
+    ("26", "39"),
+    ("27", "40"),
+    ("28", "41"),
+    ("29", "42"),
+    ("30", "43"),
+    ("31", "44"),
+    ("32", "45"),
+    ("33", "46"),
+    ("34", "47"),
+    ("35", "48"),
+    ("36", "49"),
+    ("37", "50"),
+    ("38", "51"),
+    ("39", "52"),
+    ("40", "53"),
+    ("41", "54"),
+    ("42", "55"),
+    ("43", "56"),
+    ("44", "57"),
+    ("45", "58"),
+    ("46", "59"),
+    ("47", "60"),
+    ("48", "61"),
+    ("49", "62"),
+    ("50", "63"),
+    ("51", "64"),
+    ("52", "65"),
+    ("53", "66"),
+    ("54", "67"),
+    ("55", "68"),
+    ("56", "69"),
+    ("57", "70"),
+    ("58", "71"),
+    ("59", "72"),
+    ("60", "73"),
+    ("61", "74"),
+    ("62", "75"),
+    ("63", "76"),
+    ("64", "77"),
+    ("65", "78"),
+    ("66", "79"),
+    ("67", "80"),
+    ("68", "81"),
+    ("69", "83"),
+    ("70", "84"),
+    ("71", "85"),
+    ("72", "86"),
+    ("73", "87"),
+    ("74", "88"),
+    ("75", "89"),
+    ("76", "90"),
+    ("77", "91"),
+    ("78", "92"),
+    ("79", "93"),
+    ("80", "94"),
+    ("81", "95"),
+    ("82", "96"),
+    ("83", "97"),
+    ("84", "98"),
+    ("85", "99"),
+    ("86", "100"),
+    ("87", "101"),
+    ("88", "102"),
+    ("89", "103"),
+    ("90", "104"),
+    ("91", "105"),
+    ("92", "106"),
+    ("93", "107"),
+    ("94", "108"),
+    ("95", "109"),
+    ("96", "110"),
+    ("97", "111"),
+    ("98", "112"),
+    ("99", "113"),
+    ("100", "114"),
+    ("101", "115"),
+    ("102", "116"),
+    ("103", "117"),
+    ("104", "118"),
+    ("105", "119"),
+    ("106", "120"),
+    ("107", "121"),
+    ("108", "122"),
+    ("109", "123"),
+    ("110", "124"),
+    ("111", "125"),
+    ("112", "126"),
+    ("113", "127"),
+    ("114", "128"),
+    ("115", "130"),
+    ("116", "131"),
+    ("117", "132"),
+    ("118", "133"),
+    ("119", "134"),
+    ("120", "135"),
+    ("121", "137"),
+    ("122", "138"),
+];
+
+/// Chromium versions of Samsung Internet, by the first version of each range.
+const SAMSUNG_CHROMIUM_VERSIONS: &[(&str, &str)] = &[
+    ("4", "44"),
+    ("5", "51"),
+    ("6.2", "56"),
+    ("7.2", "59"),
+    ("8.2", "63"),
+    ("9.2", "67"),
+    ("10.1", "71"),
+    ("11.1", "75"),
+    ("12", "79"),
+    ("13", "83"),
+    ("14", "87"),
+    ("15", "90"),
+    ("16", "92"),
+    ("17", "96"),
+    ("18", "99"),
+    ("19", "102"),
+    ("20", "106"),
+    ("21", "110"),
+    ("22", "111"),
+    ("23", "115"),
+    ("24", "117"),
+    ("25", "121"),
+    ("26", "122"),
+    ("27", "125"),
+    ("28", "130"),
+];
+
+/// Chromium versions of UC Browser for Android, by the first version of each range.
+const UC_CHROMIUM_VERSIONS: &[(&str, &str)] = &[("11.8", "57"), ("15.5", "78")];
+
+/// Chromium versions of QQ Browser, by the first version of each range.
+const QQ_CHROMIUM_VERSIONS: &[(&str, &str)] = &[("10.4", "57"), ("13.1", "89"), ("14.9", "118")];
+
+/// Chromium versions of Baidu Browser, by the first version of each range.
+const BAIDU_CHROMIUM_VERSIONS: &[(&str, &str)] = &[("13.18", "86")];
+
+/// Chromium versions of Opera Mobile, by the first version of each range.
+const OP_MOB_CHROMIUM_VERSIONS: &[(&str, &str)] = &[("80", "120")];
+
+/// WebKit versions of Safari, by the first version of each range.
+const SAFARI_WEBKIT_VERSIONS: &[(&str, &str)] = &[
+    ("13", "608.2"),
+    ("13.1", "609.1"),
+    ("14", "610.1"),
+    ("14.1", "611.1"),
+    ("15", "612.1"),
+    ("15.4", "613.1"),
+    ("16", "614.1"),
+    ("16.4", "615.1"),
+    ("17", "616.1"),
+    ("17.2", "617.1"),
+    ("17.4", "618.1"),
+    ("18", "619.1"),
+    ("18.2", "620.1"),
+    ("18.4", "621.1"),
+    ("26", "622.1"),
+];
+
+/// Gecko versions of KaiOS, by the first version of each range.
+const KAIOS_GECKO_VERSIONS: &[(&str, &str)] = &[("2.5", "48"), ("3", "84")];
+
+/// Trident versions of Internet Explorer.
+const IE_TRIDENT_VERSIONS: &[(&str, &str)] = &[("8", "4"), ("9", "5"), ("10", "6"), ("11", "7")];
+
+/// Mobile browsers which can fall back to desktop data with `mobileToDesktop`.
+const MOBILE_TO_DESKTOP: &[(&str, &str)] = &[
+    ("and_chr", "chrome"),
+    ("android", "chrome"),
+    ("and_ff", "firefox"),
+    ("ie_mob", "ie"),
+];
+
 #[derive(Deserialize)]
 struct Caniuse {
     agents: BTreeMap<String, Agent>,
@@ -44,6 +283,14 @@ struct Caniuse {
 
 #[derive(Deserialize)]
 struct Agent {
+    browser: String,
+    long_name: String,
+    abbr: String,
+    #[serde(rename = "type")]
+    browser_type: String,
+    prefix: String,
+    #[serde(default)]
+    prefix_exceptions: BTreeMap<String, String>,
     usage_global: BTreeMap<String, f32>,
     version_list: Vec<VersionDetail>,
 }
@@ -57,6 +304,13 @@ struct VersionDetail {
 
 #[derive(Deserialize)]
 struct Feature {
+    title: String,
+    description: String,
+    spec: String,
+    status: String,
+    categories: Vec<String>,
+    #[serde(default)]
+    keywords: String,
     stats: BTreeMap<String, IndexMap<String, String>>,
 }
 
@@ -65,7 +319,9 @@ fn main() -> Result<()> {
     build_electron_to_chromium()?;
     build_node_versions()?;
     build_node_release_schedule()?;
+    build_firefox_esr()?;
     build_caniuse()?;
+    build_mdn()?;
 
     Ok(())
 }
@@ -97,15 +353,47 @@ fn build_electron_to_chromium() -> Result<()> {
     )?)?
     .into_iter()
     .map(|(electron_version, chromium_version)| {
-        (electron_version.parse::<f32>().unwrap(), chromium_version)
+        (
+            electron_version.parse::<f32>().unwrap(),
+            electron_version,
+            chromium_version,
+        )
+    })
+    .collect::<Vec<_>>();
+    data.sort_by(|(a, ..), (b, ..)| a.total_cmp(b));
+    let (electron_versions, (electron_version_names, chromium_versions)): (
+        Vec<_>,
+        (Vec<_>, Vec<_>),
+    ) = data
+        .into_iter()
+        .map(|(version, name, chromium)| (version, (name, chromium)))
+        .unzip();
+
+    // prereleases like `28.0.0-beta.1` are excluded
+    let mut full_versions = serde_json::from_slice::<BTreeMap<String, String>>(&fs::read(
+        "vendor/electron-to-chromium/full-versions.json",
+    )?)?
+    .into_iter()
+    .filter_map(|(electron_version, chromium_version)| {
+        let numeric = electron_version
+            .split('.')
+            .map(|segment| segment.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        Some((numeric, electron_version, chromium_version))
     })
     .collect::<Vec<_>>();
-    data.sort_by(|(a, _), (b, _)| a.total_cmp(b));
-    let (electron_versions, chromium_versions): (Vec<_>, Vec<_>) = data.into_iter().unzip();
+    full_versions.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+    let full_versions = full_versions
+        .into_iter()
+        .map(|(_, electron_version, chromium_version)| {
+            quote! { (#electron_version, #chromium_version) }
+        });
 
     let code = quote! {
         static ELECTRON_VERSIONS: &[f32] = &[ #(#electron_versions),* ];
+        static ELECTRON_VERSION_NAMES: &[&str] = &[ #(#electron_version_names),* ];
         static CHROMIUM_VERSIONS: &[&str] = &[ #(#chromium_versions),* ];
+        static ELECTRON_FULL_VERSIONS: &[(&str, &str)] = &[ #(#full_versions),* ];
     };
 
     fs::write(path, code.to_string())?;
@@ -117,6 +405,7 @@ fn build_node_versions() -> Result<()> {
     #[derive(Deserialize)]
     struct NodeRelease {
         version: String,
+        v8: String,
     }
 
     let path = format!("{OUT_DIR}/node-versions.rs");
@@ -124,11 +413,15 @@ fn build_node_versions() -> Result<()> {
     let releases: Vec<NodeRelease> =
         serde_json::from_slice(&fs::read("vendor/node-releases/data/processed/envs.json")?)?;
 
-    let versions = releases.into_iter().map(|release| release.version);
+    let (versions, v8_versions): (Vec<_>, Vec<_>) = releases
+        .into_iter()
+        .map(|release| (release.version, release.v8))
+        .unzip();
     fs::write(
         path,
         quote! {
             static NODE_VERSIONS: &[&str] = &[#(#versions),*];
+            static NODE_V8_VERSIONS: &[&str] = &[#(#v8_versions),*];
         }
         .to_string(),
     )?;
@@ -142,7 +435,10 @@ fn build_node_release_schedule() -> Result<()> {
     #[derive(Deserialize)]
     struct NodeRelease {
         start: String,
+        lts: Option<String>,
+        maintenance: Option<String>,
         end: String,
+        codename: Option<String>,
     }
 
     let path = format!("{OUT_DIR}/node-release-schedule.rs");
@@ -150,53 +446,161 @@ fn build_node_release_schedule() -> Result<()> {
     let schedule: BTreeMap<String, NodeRelease> = serde_json::from_slice(&fs::read(
         "vendor/node-releases/data/release-schedule/release-schedule.json",
     )?)?;
-    let mut versions = schedule
+    let mut releases = schedule
         .into_iter()
-        .map(|(version, NodeRelease { start, end })| {
-            let date_format = "%Y-%m-%d";
-            let start = NaiveDate::parse_from_str(&start, date_format)
-                .unwrap()
-                .and_hms_opt(0, 0, 0)
-                .unwrap();
-            let end = NaiveDate::parse_from_str(&end, date_format)
-                .unwrap()
-                .and_hms_opt(0, 0, 0)
-                .unwrap();
-
-            (version, (start, end))
+        .map(|(version, release)| {
+            let version = version.trim_start_matches('v').to_owned();
+            let numeric = version
+                .split('.')
+                .map(|segment| segment.parse::<u32>().unwrap())
+                .collect::<Vec<_>>();
+            (numeric, version, release)
         })
         .collect::<Vec<_>>();
-    // filter by end date to quickly reduce scope
-    versions.sort_by_key(|(_, (_, end))| *end);
+    releases.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+
+    let date = |date: &str| {
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+        let year = date.year();
+        let month = date.month();
+        let day = date.day();
+        quote! { chrono::NaiveDate::from_ymd_opt(#year, #month, #day).unwrap() }
+    };
+    let optional_date = |optional: &Option<String>| match optional {
+        Some(value) => {
+            let value = date(value);
+            quote! { Some(#value) }
+        }
+        None => quote! { None },
+    };
 
-    let (versions, dates): (Vec<_>, Vec<_>) = versions
-        .into_iter()
-        .map(|(version, (start, end))| {
-            let version = version.trim_start_matches('v');
+    let releases = releases.into_iter().map(|(_, version, release)| {
+        let start = date(&release.start);
+        let lts = optional_date(&release.lts);
+        let maintenance = optional_date(&release.maintenance);
+        let end = date(&release.end);
+        let codename = match &release.codename {
+            Some(codename) => quote! { Some(#codename) },
+            None => quote! { None },
+        };
+        quote! {
+            ReleaseSchedule {
+                version: #version,
+                start: #start,
+                lts: #lts,
+                maintenance: #maintenance,
+                end: #end,
+                codename: #codename,
+            }
+        }
+    });
+
+    fs::write(
+        path,
+        quote! {
+            static NODE_RELEASE_SCHEDULE: &[ReleaseSchedule] = &[#(#releases),*];
+        }
+        .to_string(),
+    )?;
 
-            let start_year = start.year();
-            let start_month = start.month();
-            let start_day = start.day();
-            let end_year = end.year();
-            let end_month = end.month();
-            let end_day = end.day();
+    Ok(())
+}
 
-            let date = quote! {
-                (
-                    chrono::NaiveDate::from_ymd_opt(#start_year, #start_month, #start_day).unwrap(),
-                    chrono::NaiveDate::from_ymd_opt(#end_year, #end_month, #end_day).unwrap(),
-                )
-            };
+fn build_firefox_esr() -> Result<()> {
+    use chrono::{Datelike, NaiveDate};
+
+    #[derive(Deserialize)]
+    struct Firefox {
+        releases: BTreeMap<String, Release>,
+    }
+
+    #[derive(Deserialize)]
+    struct Release {
+        category: String,
+        date: String,
+        version: String,
+    }
+
+    let path = format!("{OUT_DIR}/firefox-esr.rs");
+
+    let firefox: Firefox =
+        serde_json::from_slice(&fs::read("vendor/product-details/public/1.0/firefox.json")?)?;
+    let major_releases: BTreeMap<String, String> = serde_json::from_slice(&fs::read(
+        "vendor/product-details/public/1.0/firefox_history_major_releases.json",
+    )?)?;
+    // such as `FIREFOX_ESR` and `FIREFOX_ESR115`, which are still maintained
+    let current_versions: BTreeMap<String, String> = serde_json::from_slice(&fs::read(
+        "vendor/product-details/public/1.0/firefox_versions.json",
+    )?)?;
+    let maintained = current_versions
+        .iter()
+        .filter(|(key, _)| key.starts_with("FIREFOX_ESR"))
+        .filter_map(|(_, version)| version.split('.').next()?.parse::<u32>().ok())
+        .collect::<Vec<_>>();
+    let major_releases = major_releases
+        .values()
+        .map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d"))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    // ESR major version and dates of its first and last releases
+    let mut esr = BTreeMap::<u32, (NaiveDate, NaiveDate)>::new();
+    for release in firefox.releases.into_values() {
+        let Some(version) = release.version.strip_suffix("esr") else {
+            continue;
+        };
+        if release.category != "esr" {
+            continue;
+        }
+        let major = version.split('.').next().unwrap().parse::<u32>()?;
+        let date = NaiveDate::parse_from_str(&release.date, "%Y-%m-%d")?;
+        esr.entry(major)
+            .and_modify(|(first, last)| {
+                *first = date.min(*first);
+                *last = date.max(*last);
+            })
+            .or_insert((date, date));
+    }
 
-            (version.to_owned(), date)
+    let date = |date: NaiveDate| {
+        let year = date.year();
+        let month = date.month();
+        let day = date.day();
+        quote! { chrono::NaiveDate::from_ymd_opt(#year, #month, #day).unwrap() }
+    };
+    let releases = esr
+        .iter()
+        .map(|(major, (start, last))| {
+            // An ESR which is no longer maintained reached end-of-life
+            // when the first major release after its last update shipped,
+            // so extended support like ESR 115 follows Mozilla's data.
+            let end = if maintained.contains(major) {
+                None
+            } else {
+                major_releases.iter().find(|date| *date > last).copied()
+            };
+            let version = major.to_string();
+            let start = date(*start);
+            let end = match end {
+                Some(end) => {
+                    let end = date(end);
+                    quote! { Some(#end) }
+                }
+                None => quote! { None },
+            };
+            quote! {
+                EsrRelease {
+                    version: #version,
+                    start: #start,
+                    end: #end,
+                }
+            }
         })
-        .unzip();
+        .collect::<Vec<_>>();
 
     fs::write(
         path,
         quote! {
-            static NODE_RELEASE_VERSIONS: &[&str] = &[#(#versions),*];
-            static NODE_RELEASE_SCHEDULE: &[(chrono::NaiveDate, chrono::NaiveDate)] = &[#(#dates),*];
+            static FIREFOX_ESR_RELEASES: &[EsrRelease] = &[#(#releases),*];
         }
         .to_string(),
     )?;
@@ -209,6 +613,79 @@ fn build_caniuse() -> Result<()> {
 
     let mut strpool = StrPool::default();
 
+    // caniuse browser registry
+    let browser_ids = {
+        for (alias, name) in BROWSER_ALIASES.iter().chain(MOBILE_TO_DESKTOP) {
+            if !data.agents.contains_key(*name) {
+                anyhow::bail!("browser alias '{alias}' refers to unknown agent '{name}'");
+            }
+        }
+
+        let browser_ids = data
+            .agents
+            .keys()
+            .enumerate()
+            .map(|(id, name)| Ok((name.as_str(), u8::try_from(id)?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        let names = data.agents.keys();
+        let mut aliases = BROWSER_ALIASES.to_vec();
+        aliases.sort_unstable();
+        let aliases = aliases
+            .iter()
+            .map(|(alias, name)| quote! { (#alias, #name) });
+        // display names like "Samsung Internet" are accepted as names,
+        // unless they're ambiguous or conflict with existing names
+        let mut display_names = HashMap::<String, Option<&str>>::new();
+        for (name, agent) in &data.agents {
+            for display_name in [&agent.browser, &agent.long_name] {
+                let display_name = display_name.to_ascii_lowercase();
+                if data.agents.contains_key(&display_name)
+                    || BROWSER_ALIASES
+                        .iter()
+                        .any(|(alias, _)| *alias == display_name)
+                {
+                    continue;
+                }
+                display_names
+                    .entry(display_name)
+                    .and_modify(|entry| {
+                        if *entry != Some(name.as_str()) {
+                            *entry = None;
+                        }
+                    })
+                    .or_insert(Some(name));
+            }
+        }
+        let mut display_names = display_names
+            .into_iter()
+            .filter_map(|(display_name, name)| name.map(|name| (display_name, name)))
+            .collect::<Vec<_>>();
+        display_names.sort_unstable();
+        let display_names = display_names
+            .iter()
+            .map(|(display_name, name)| quote! { (#display_name, #name) });
+        let mut mobile_to_desktop = MOBILE_TO_DESKTOP.to_vec();
+        mobile_to_desktop.sort_unstable();
+        let mobile_to_desktop = mobile_to_desktop
+            .iter()
+            .map(|(mobile, desktop)| quote! { (#mobile, #desktop) });
+
+        fs::write(
+            format!("{OUT_DIR}/caniuse-browser-registry.rs"),
+            quote! {
+                static BROWSER_NAMES: &[&str] = &[#(#names),*];
+                static BROWSER_ALIASES: &[(&str, &str)] = &[#(#aliases),*];
+                static BROWSER_DISPLAY_NAMES: &[(&str, &str)] = &[#(#display_names),*];
+                static MOBILE_TO_DESKTOP: &[(&str, &str)] = &[#(#mobile_to_desktop),*];
+            }
+            .to_string(),
+        )?;
+
+        browser_ids
+    };
+    let encode_browser_name = |name: &str| browser_ids[name];
+
     // caniuse browsers
     {
         let mut versions = Vec::new();
@@ -217,18 +694,56 @@ fn build_caniuse() -> Result<()> {
         for (name, agent) in &data.agents {
             let name_str_id = strpool.insert(name);
             let start: u32 = versions.len().try_into().unwrap();
+            let engine = BROWSER_ENGINES
+                .iter()
+                .find(|(browser, _)| browser == name)
+                .map(|&(_, engine)| engine);
+            if engine.is_none() {
+                // new browsers shouldn't break data generation, so leave their engines unknown
+                eprintln!("warning: unknown engine of browser '{name}'");
+            }
+            let legacy_engine = LEGACY_ENGINES.iter().find(|(browser, ..)| browser == name);
 
             for version in &agent.version_list {
                 let version_str_id = strpool.insert(&version.version);
                 let usage = version.global_usage;
                 let date = version.release_date.unwrap_or_default();
                 let released = version.release_date.is_some();
+                let engine = match legacy_engine {
+                    Some(&(_, legacy_engine, last_version))
+                        if compare_version(&version.version, last_version).is_le() =>
+                    {
+                        Some(legacy_engine)
+                    }
+                    _ => engine,
+                };
+                let engine_version =
+                    engine.and_then(|engine| engine_version(name, &version.version, engine));
+                if engine == Some("Blink") && engine_version.is_none() {
+                    // the tables of Chromium versions should be updated for new versions
+                    eprintln!(
+                        "warning: unknown Chromium version of browser '{name} {}'",
+                        version.version
+                    );
+                }
+                let engine_version = engine_version
+                    .map(|engine_version| strpool.insert_cow(Cow::Owned(engine_version)))
+                    .unwrap_or_else(|| strpool.insert(""));
+                let engine = match engine {
+                    Some(engine) => {
+                        let engine = quote::format_ident!("{engine}");
+                        quote! { Some(Engine::#engine) }
+                    }
+                    None => quote! { None },
+                };
 
                 versions.push(quote! {
                     VersionDetail {
                         version: PooledStr(#version_str_id),
                         release_date: #date,
                         released: #released,
+                        engine: #engine,
+                        engine_version: PooledStr(#engine_version),
                         global_usage: #usage,
                     }
                 });
@@ -258,6 +773,61 @@ fn build_caniuse() -> Result<()> {
         )?;
     }
 
+    // caniuse browser metadata
+    {
+        let metas = data.agents.iter().map(|(name, agent)| {
+            let Agent {
+                browser,
+                long_name,
+                abbr,
+                browser_type,
+                ..
+            } = agent;
+            let browser_type = match browser_type.as_str() {
+                "mobile" => quote! { BrowserType::Mobile },
+                _ => quote! { BrowserType::Desktop },
+            };
+            quote! {
+                (#name, BrowserMeta {
+                    display_name: #browser,
+                    long_name: #long_name,
+                    abbr: #abbr,
+                    browser_type: #browser_type,
+                })
+            }
+        });
+
+        fs::write(
+            format!("{OUT_DIR}/caniuse-browser-meta.rs"),
+            quote! {
+                static BROWSER_META: &[(&str, BrowserMeta)] = &[#(#metas),*];
+            }
+            .to_string(),
+        )?;
+    }
+
+    // caniuse browser prefixes
+    {
+        let prefixes = data.agents.iter().map(|(name, agent)| {
+            let prefix = &agent.prefix;
+            let exceptions = agent
+                .prefix_exceptions
+                .iter()
+                .map(|(version, prefix)| quote! { (#version, #prefix) });
+            quote! {
+                (#name, (#prefix, &[#(#exceptions),*]))
+            }
+        });
+
+        fs::write(
+            format!("{OUT_DIR}/caniuse-browser-prefixes.rs"),
+            quote! {
+                static BROWSER_PREFIXES: &[(&str, (&str, PrefixExceptions))] = &[#(#prefixes),*];
+            }
+            .to_string(),
+        )?;
+    }
+
     // caniuse usage
     {
         let mut global_usage = Vec::new();
@@ -296,32 +866,65 @@ fn build_caniuse() -> Result<()> {
         let mut stats = Vec::new();
         let mut versions = Vec::new();
         let mut flags = Vec::new();
+        let mut notes_index = Vec::new();
+        let mut notes_range = Vec::new();
+        let mut notes = Vec::new();
 
         for (name, feature) in &data.data {
             let start = stats.len();
             for (browser, ver) in &feature.stats {
                 let mut list = ver
                     .iter()
-                    .map(|(version, flags)| {
+                    .map(|(version, stat)| {
                         let version_str_id = strpool.insert(version);
 
                         let mut bit: u8 = 0;
-                        if flags.contains('y') {
-                            bit |= 1;
-                        }
-                        if flags.contains('a') {
-                            bit |= 2;
+                        let mut note_numbers = Vec::new();
+                        for flag in stat.split_whitespace() {
+                            match flag {
+                                "y" => bit |= flags::Y,
+                                "a" => bit |= flags::A,
+                                "n" => bit |= flags::N,
+                                "x" => bit |= flags::X,
+                                "d" => bit |= flags::D,
+                                "p" => bit |= flags::P,
+                                "u" => bit |= flags::U,
+                                _ => {
+                                    let note = flag
+                                        .strip_prefix('#')
+                                        .and_then(|note| note.parse::<u32>().ok())
+                                        .ok_or_else(|| {
+                                            anyhow::anyhow!(
+                                                "unsupported flag '{flag}' of feature '{name}'"
+                                            )
+                                        })?;
+                                    note_numbers.push(note);
+                                }
+                            }
                         }
-                        (version_str_id, bit)
+                        note_numbers.sort_unstable();
+                        note_numbers.dedup();
+                        Ok((version_str_id, bit, note_numbers))
                     })
-                    .collect::<Vec<_>>();
+                    .collect::<Result<Vec<_>>>()?;
 
                 // we only use `.get()`, so the original order does not need to be preserved here
-                list.sort_by_key(|(x, _)| strpool.get(*x));
+                list.sort_by_key(|(x, ..)| strpool.get(*x));
 
                 let start = versions.len();
-                versions.extend(list.iter().map(|(x, _)| *x));
-                flags.extend(list.iter().map(|(_, y)| *y));
+                versions.extend(list.iter().map(|(x, ..)| *x));
+                flags.extend(list.iter().map(|(_, y, _)| *y));
+                // notes are rare, so only entries having notes are stored, keyed by entry index
+                for (index, (.., note_numbers)) in list.iter().enumerate() {
+                    if note_numbers.is_empty() {
+                        continue;
+                    }
+                    let notes_start: u32 = notes.len().try_into().unwrap();
+                    notes.extend_from_slice(note_numbers);
+                    let notes_end: u32 = notes.len().try_into().unwrap();
+                    notes_index.push(u32::try_from(start + index).unwrap());
+                    notes_range.push([notes_start, notes_end]);
+                }
                 let end = versions.len();
 
                 stats.push((browser.as_str(), start, end));
@@ -364,6 +967,18 @@ fn build_caniuse() -> Result<()> {
             format!("{OUT_DIR}/caniuse-feature-versionindex.u32seq"),
             stats_list.iter().flatten().copied(),
         )?;
+        let notes_index_len = write_u32(
+            format!("{OUT_DIR}/caniuse-feature-notesindex.u32seq"),
+            notes_index.iter().copied(),
+        )?;
+        let notes_range_len = write_u32(
+            format!("{OUT_DIR}/caniuse-feature-notesrange.u32seq"),
+            notes_range.iter().flatten().copied(),
+        )?;
+        let notes_len = write_u32(
+            format!("{OUT_DIR}/caniuse-feature-notes.u32seq"),
+            notes.iter().copied(),
+        )?;
 
         fs::write(
             format!("{OUT_DIR}/caniuse-feature-flags.bin"),
@@ -396,12 +1011,63 @@ fn build_caniuse() -> Result<()> {
                     >(*include_bytes!("caniuse-feature-versionindex.u32seq"))
                 };
 
+                static FEATURES_STAT_NOTES_INDEX: &[U32; #notes_index_len / core::mem::size_of::<U32>()] = unsafe {
+                    &core::mem::transmute::<
+                        [u8; #notes_index_len],
+                        [U32; #notes_index_len / core::mem::size_of::<U32>()]
+                    >(*include_bytes!("caniuse-feature-notesindex.u32seq"))
+                };
+                static FEATURES_STAT_NOTES_RANGE: &[PairU32; #notes_range_len / core::mem::size_of::<PairU32>()] = unsafe {
+                    &core::mem::transmute::<
+                        [u8; #notes_range_len],
+                        [PairU32; #notes_range_len / core::mem::size_of::<PairU32>()]
+                    >(*include_bytes!("caniuse-feature-notesrange.u32seq"))
+                };
+                static FEATURES_STAT_NOTES: &[U32; #notes_len / core::mem::size_of::<U32>()] = unsafe {
+                    &core::mem::transmute::<
+                        [u8; #notes_len],
+                        [U32; #notes_len / core::mem::size_of::<U32>()]
+                    >(*include_bytes!("caniuse-feature-notes.u32seq"))
+                };
+
                 static FEATURES_STAT_FLAGS: &[u8] = include_bytes!("caniuse-feature-flags.bin");
                 static FEATURES_STAT_BROWSERS: &[u8] = include_bytes!("caniuse-feature-browsers.bin");
             }.to_string()
         )?;
     }
 
+    // caniuse feature metadata, in the same order as features
+    {
+        let metas = data.data.values().map(|feature| {
+            let Feature {
+                title,
+                description,
+                spec,
+                status,
+                categories,
+                keywords,
+                ..
+            } = feature;
+            quote! {
+                FeatureMeta {
+                    title: #title,
+                    description: #description,
+                    spec: #spec,
+                    status: #status,
+                    categories: &[#(#categories),*],
+                    keywords: #keywords,
+                }
+            }
+        });
+        fs::write(
+            format!("{OUT_DIR}/caniuse-feature-meta.rs"),
+            quote! {
+                static FEATURES_META: &[FeatureMeta] = &[#(#metas),*];
+            }
+            .to_string(),
+        )?;
+    }
+
     // caniuse region
     {
         #[derive(Deserialize)]
@@ -514,6 +1180,57 @@ fn parse_caniuse_global() -> Result<Caniuse> {
     )?)?)
 }
 
+/// Get version of the rendering engine of a browser version, such as Chromium 115 for Samsung Internet 23.
+fn engine_version(name: &str, version: &str, engine: &str) -> Option<String> {
+    let major = || {
+        version
+            .split(['.', '-'])
+            .next()
+            .and_then(|major| major.parse::<u32>().ok())
+    };
+    let lookup = |table: &[(&str, &str)]| {
+        table
+            .iter()
+            .rev()
+            .find(|(since, _)| compare_version(version, since).is_ge())
+            .map(|(_, engine_version)| engine_version.to_string())
+    };
+
+    match (name, engine) {
+        ("and_chr" | "android" | "chrome" | "edge", "Blink") => major().map(|v| v.to_string()),
+        ("opera", "Blink") => major().and_then(|major| {
+            OPERA_CHROMIUM_VERSIONS
+                .iter()
+                .find(|(opera, _)| opera.parse() == Ok(major))
+                .map(|(_, chromium)| chromium.to_string())
+        }),
+        ("samsung", _) => lookup(SAMSUNG_CHROMIUM_VERSIONS),
+        ("and_uc", _) => lookup(UC_CHROMIUM_VERSIONS),
+        ("and_qq", _) => lookup(QQ_CHROMIUM_VERSIONS),
+        ("baidu", _) => lookup(BAIDU_CHROMIUM_VERSIONS),
+        ("op_mob", "Blink") => lookup(OP_MOB_CHROMIUM_VERSIONS),
+        ("safari" | "ios_saf", _) => lookup(SAFARI_WEBKIT_VERSIONS),
+        ("edge", "EdgeHtml") | ("and_ff" | "firefox", _) => major().map(|v| v.to_string()),
+        ("kaios", _) => lookup(KAIOS_GECKO_VERSIONS),
+        ("ie" | "ie_mob", _) => lookup(IE_TRIDENT_VERSIONS),
+        _ => None,
+    }
+}
+
+/// Compare versions like `4.4.3-4.4.4` numerically by the lower bound.
+fn compare_version(a: &str, b: &str) -> std::cmp::Ordering {
+    let parse = |version: &str| {
+        version
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|n| n.parse::<u32>().unwrap_or_default())
+            .collect::<Vec<_>>()
+    };
+    parse(a).cmp(&parse(b))
+}
+
 fn write_u32(path: String, iter: impl Iterator<Item = u32>) -> io::Result<usize> {
     let fd = fs::File::create(path)?;
     let mut fd = io::BufWriter::new(fd);
@@ -562,3 +1279,149 @@ impl<'s> StrPool<'s> {
         &self.pool[(offset as usize)..][..(len as usize)]
     }
 }
+
+fn build_mdn() -> Result<()> {
+    use serde_json::Value;
+
+    /// Feature path to support statements (added, removed and flags) by caniuse browser name.
+    type MdnFeatures = BTreeMap<String, BTreeMap<&'static str, Vec<(String, String, u8)>>>;
+
+    #[derive(Deserialize)]
+    struct Statement {
+        version_added: Value,
+        #[serde(default)]
+        version_removed: Value,
+        #[serde(default)]
+        partial_implementation: bool,
+        #[serde(default)]
+        prefix: Option<String>,
+        #[serde(default)]
+        alternative_name: Option<String>,
+        #[serde(default)]
+        flags: Vec<Value>,
+    }
+
+    /// Convert a BCD version like `≤37` into a plain version.
+    /// `None` means the version is unknown or only in preview builds.
+    fn normalize_version(version: &Value) -> Option<Option<String>> {
+        match version {
+            Value::String(version) if version == "preview" => None,
+            Value::String(version) => Some(Some(version.trim_start_matches('≤').to_owned())),
+            Value::Bool(true) => Some(Some("0".into())),
+            Value::Bool(false) | Value::Null => Some(None),
+            _ => None,
+        }
+    }
+
+    fn collect_features(
+        path: String,
+        value: &serde_json::Map<String, Value>,
+        features: &mut MdnFeatures,
+    ) -> Result<()> {
+        for (key, value) in value {
+            let Value::Object(value) = value else {
+                continue;
+            };
+            if key == "__compat" {
+                let Some(Value::Object(support)) = value.get("support") else {
+                    continue;
+                };
+                let browsers = features.entry(path.clone()).or_default();
+                for (mdn_name, name) in MDN_BROWSERS {
+                    let statements = match support.get(*mdn_name) {
+                        Some(Value::Array(statements)) => statements.clone(),
+                        Some(statement) => vec![statement.clone()],
+                        None => continue,
+                    };
+                    for statement in statements {
+                        let statement: Statement = serde_json::from_value(statement)?;
+                        // unknown or preview-only versions can't be compared, so skip them
+                        let Some(Some(added)) = normalize_version(&statement.version_added) else {
+                            continue;
+                        };
+                        let removed = match &statement.version_removed {
+                            Value::Bool(true) => continue,
+                            version => normalize_version(version).flatten().unwrap_or_default(),
+                        };
+                        let mut bit = if !statement.flags.is_empty() {
+                            // behind a flag, so it's disabled by default
+                            flags::N | flags::D
+                        } else if statement.partial_implementation {
+                            flags::A
+                        } else {
+                            flags::Y
+                        };
+                        if statement.prefix.is_some() || statement.alternative_name.is_some() {
+                            bit |= flags::X;
+                        }
+                        browsers
+                            .entry(*name)
+                            .or_default()
+                            .push((added, removed, bit));
+                    }
+                }
+            } else {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                collect_features(path, value, features)?;
+            }
+        }
+        Ok(())
+    }
+
+    let mut features = BTreeMap::new();
+    // the dataset is optional, so it's empty when the package isn't installed
+    if let Ok(bytes) = fs::read("node_modules/@mdn/browser-compat-data/data.json") {
+        let Value::Object(data) = serde_json::from_slice(&bytes)? else {
+            anyhow::bail!("invalid MDN browser-compat-data");
+        };
+        for (category, value) in &data {
+            if matches!(
+                category.as_str(),
+                "__meta" | "browsers" | "webdriver" | "webextensions"
+            ) {
+                continue;
+            }
+            if let Value::Object(value) = value {
+                collect_features(category.clone(), value, &mut features)?;
+            }
+        }
+    } else {
+        eprintln!(
+            "warning: MDN browser-compat-data isn't installed, so MDN data will be empty; \
+             install it with `pnpm install` first"
+        );
+    }
+
+    let features = features
+        .iter()
+        .filter(|(_, browsers)| !browsers.is_empty())
+        .map(|(path, browsers)| {
+            let browsers = browsers.iter().map(|(name, statements)| {
+                let statements = statements.iter().map(|(added, removed, flags)| {
+                    quote! {
+                        SupportStatement {
+                            version_added: #added,
+                            version_removed: #removed,
+                            flags: #flags,
+                        }
+                    }
+                });
+                quote! { (#name, &[#(#statements),*]) }
+            });
+            quote! { (#path, &[#(#browsers),*]) }
+        });
+
+    fs::write(
+        format!("{OUT_DIR}/mdn-features.rs"),
+        quote! {
+            static MDN_FEATURES: &[(&str, BrowserSupport)] = &[#(#features),*];
+        }
+        .to_string(),
+    )?;
+
+    Ok(())
+}
@@ -45,6 +45,16 @@ struct PackageJson {
     browserslist: Option<PkgConfig>,
 }
 
+#[derive(Deserialize)]
+struct PackageJsonEngines {
+    engines: Option<Engines>,
+}
+
+#[derive(Deserialize)]
+struct Engines {
+    node: Option<String>,
+}
+
 const ERR_DUP_PLAIN: &str = "'browserslist' file";
 const ERR_DUP_RC: &str = "'.browserslistrc' file";
 const ERR_DUP_PKG: &str = "'package.json' file with `browserslist` field";
@@ -82,11 +92,7 @@ pub fn load(opts: &Opts) -> Result<Vec<String>, Error> {
             }
         }
     } else {
-        let path = match &opts.path {
-            Some(path) => PathBuf::from(path),
-            None => env::current_dir().map_err(|_| Error::FailedToAccessCurrentDir)?,
-        };
-        match find_config(path)? {
+        match find_config(search_path(opts)?)? {
             Either::Left(s) => {
                 let config = parse(&s, get_env(opts), opts.throw_on_missing)?;
                 Ok(config.env.unwrap_or(config.defaults))
@@ -179,7 +185,68 @@ fn find_config<P: AsRef<Path>>(path: P) -> Result<Either<String, PkgConfig>, Err
     Ok(Either::Left(String::from("defaults")))
 }
 
-fn get_env(opts: &Opts) -> Cow<str> {
+/// Find the Node.js version which the project pins, from the nearest directory which has
+/// `.nvmrc`, `.node-version`, `.tool-versions` or `package.json` with `engines.node` field.
+///
+/// The result is the raw content, such as `18`, `lts/hydrogen` or `>=18.17 <23`.
+pub fn find_node_version(opts: &Opts) -> Result<Option<String>, Error> {
+    for dir in search_path(opts)?.ancestors() {
+        let version = [".nvmrc", ".node-version"]
+            .iter()
+            .find_map(|file_name| {
+                let content = fs::read_to_string(dir.join(file_name)).ok()?;
+                content
+                    .lines()
+                    .map(|line| line.split('#').next().unwrap_or_default().trim())
+                    .find(|line| !line.is_empty())
+                    .map(String::from)
+            })
+            .or_else(|| {
+                let content = fs::read_to_string(dir.join(".tool-versions")).ok()?;
+                content.lines().find_map(|line| {
+                    let mut fields = line.split_whitespace();
+                    matches!(fields.next(), Some("nodejs" | "node"))
+                        .then(|| fields.next().map(String::from))
+                        .flatten()
+                })
+            })
+            .or_else(|| read_node_engines(dir));
+        if version.is_some() {
+            return Ok(version);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Read `engines.node` field of the nearest `package.json`.
+pub fn find_node_engines(opts: &Opts) -> Result<String, Error> {
+    let path = search_path(opts)?;
+    let dir = path
+        .ancestors()
+        .find(|dir| dir.join("package.json").is_file())
+        .ok_or_else(|| Error::MissingPkg(format!("{}", path.display())))?;
+    read_node_engines(dir).ok_or_else(|| {
+        Error::MissingEnginesInPkg(format!("{}", dir.join("package.json").display()))
+    })
+}
+
+fn search_path(opts: &Opts) -> Result<PathBuf, Error> {
+    match &opts.path {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => env::current_dir().map_err(|_| Error::FailedToAccessCurrentDir),
+    }
+}
+
+fn read_node_engines(dir: &Path) -> Option<String> {
+    let content = fs::read(dir.join("package.json")).ok()?;
+    serde_json::from_slice::<PackageJsonEngines>(&content)
+        .ok()?
+        .engines?
+        .node
+}
+
+fn get_env(opts: &Opts) -> Cow<'_, str> {
     opts.env
         .as_ref()
         .map(Cow::from)
@@ -0,0 +1,407 @@
+//! Typed access to the bundled Can I Use, Node.js and Electron data.
+//!
+//! This is similar to `browserslist.data` of the JavaScript-based implementation.
+//!
+//! ```
+//! use browserslist::{data, Opts};
+//!
+//! assert!(data::browsers().any(|name| name == "firefox"));
+//!
+//! let version = data::browser_version("ff", "93", &Opts::default())
+//!     .unwrap()
+//!     .unwrap();
+//! assert_eq!(version.name(), "firefox");
+//! assert_eq!(version.version(), "93");
+//! assert!(version.released());
+//! ```
+
+use crate::{error::Error, opts::Opts};
+use browserslist_data::{
+    caniuse::{
+        self, get_browser_meta, get_browser_stat, normalize_version, BrowserMeta, VersionDetail,
+    },
+    electron,
+    node::{self, ReleaseSchedule},
+};
+use chrono::{DateTime, NaiveDate};
+use std::borrow::Cow;
+
+pub use browserslist_data::caniuse::{BrowserType, Engine};
+
+#[derive(Clone, Copy, Debug)]
+/// Metadata of a browser in Can I Use data.
+pub struct BrowserInfo {
+    name: &'static str,
+    meta: BrowserMeta,
+}
+
+impl BrowserInfo {
+    #[inline]
+    /// Return browser name, such as `and_chr`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline]
+    /// Return display name, such as `Chrome for Android`.
+    pub fn display_name(&self) -> &'static str {
+        self.meta.display_name
+    }
+
+    #[inline]
+    /// Return long name, such as `Internet Explorer` for `ie`.
+    pub fn long_name(&self) -> &'static str {
+        self.meta.long_name
+    }
+
+    #[inline]
+    /// Return abbreviation, such as `Chr/And.`.
+    pub fn abbr(&self) -> &'static str {
+        self.meta.abbr
+    }
+
+    #[inline]
+    /// Return whether it's a desktop browser or a mobile browser.
+    pub fn browser_type(&self) -> BrowserType {
+        self.meta.browser_type
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// A version of a browser in Can I Use data.
+pub struct BrowserVersion {
+    name: &'static str,
+    detail: &'static VersionDetail,
+}
+
+impl BrowserVersion {
+    #[inline]
+    /// Return browser name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline]
+    /// Return version string.
+    pub fn version(&self) -> &'static str {
+        self.detail.version()
+    }
+
+    /// Return release date, or `None` if this version hasn't been released.
+    pub fn release_date(&self) -> Option<NaiveDate> {
+        self.released()
+            .then(|| DateTime::from_timestamp(self.detail.release_date, 0))
+            .flatten()
+            .map(|date| date.date_naive())
+    }
+
+    #[inline]
+    /// Return whether this version has been released.
+    pub fn released(&self) -> bool {
+        self.detail.released
+    }
+
+    #[inline]
+    /// Return global usage of this version in percentage.
+    pub fn global_usage(&self) -> f32 {
+        self.detail.global_usage
+    }
+
+    #[inline]
+    /// Return rendering engine of this version, or `None` if it's unknown.
+    pub fn engine(&self) -> Option<Engine> {
+        self.detail.engine
+    }
+
+    #[inline]
+    /// Return version of the rendering engine, such as `115` for Samsung Internet 23,
+    /// or `None` if it's unknown.
+    pub fn engine_version(&self) -> Option<&'static str> {
+        self.detail.engine_version()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Release schedule of a Node.js major version.
+pub struct NodeRelease {
+    schedule: &'static ReleaseSchedule,
+}
+
+impl NodeRelease {
+    #[inline]
+    /// Return major version, such as `18`.
+    pub fn version(&self) -> &'static str {
+        self.schedule.version
+    }
+
+    #[inline]
+    /// Return date of the initial release.
+    pub fn start(&self) -> NaiveDate {
+        self.schedule.start
+    }
+
+    #[inline]
+    /// Return date of entering active LTS, or `None` if it never becomes LTS.
+    pub fn lts(&self) -> Option<NaiveDate> {
+        self.schedule.lts
+    }
+
+    #[inline]
+    /// Return date of entering maintenance.
+    pub fn maintenance(&self) -> Option<NaiveDate> {
+        self.schedule.maintenance
+    }
+
+    #[inline]
+    /// Return end-of-life date.
+    pub fn end(&self) -> NaiveDate {
+        self.schedule.end
+    }
+
+    #[inline]
+    /// Return LTS codename, such as `Hydrogen`.
+    pub fn codename(&self) -> Option<&'static str> {
+        self.schedule.codename
+    }
+
+    /// Return whether this major version is LTS and hasn't reached end-of-life at the given date.
+    pub fn is_lts_at(&self, date: NaiveDate) -> bool {
+        self.lts().is_some_and(|lts| lts <= date) && date < self.end()
+    }
+
+    /// Return the latest Node.js version of this major version, such as `18.20.4`.
+    pub fn latest_version(&self) -> Option<&'static str> {
+        let major = self.version();
+        node::versions().iter().rev().copied().find(|version| {
+            version
+                .strip_prefix(major)
+                .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+}
+
+/// List names of all browsers in Can I Use data.
+///
+/// ```
+/// use browserslist::data;
+///
+/// assert!(data::browsers().any(|name| name == "and_chr"));
+/// ```
+pub fn browsers() -> impl Iterator<Item = &'static str> {
+    caniuse::iter_browser_stat(false).map(|(name, _)| name)
+}
+
+/// Get metadata of the given browser.
+///
+/// Browser name can be an alias like `fx` or a display name like `Samsung Internet`.
+///
+/// ```
+/// use browserslist::data::{self, BrowserType};
+///
+/// let info = data::browser_info("and_chr").unwrap();
+/// assert_eq!(info.display_name(), "Chrome for Android");
+/// assert_eq!(info.browser_type(), BrowserType::Mobile);
+///
+/// assert_eq!(data::browser_info("Samsung Internet").unwrap().name(), "samsung");
+/// ```
+pub fn browser_info(name: &str) -> Result<BrowserInfo, Error> {
+    get_browser_stat(name, false)
+        .and_then(|(name, _)| get_browser_meta(name).map(|meta| BrowserInfo { name, meta }))
+        .ok_or_else(|| Error::BrowserNotFound(name.to_string()))
+}
+
+/// List all versions of the given browser, from oldest to newest.
+///
+/// Browser name can be an alias like `fx` or `ios`.
+/// The option `mobile_to_desktop` is respected.
+///
+/// ```
+/// use browserslist::{data, Opts};
+///
+/// let versions = data::browser_versions("ie", &Opts::default()).unwrap();
+/// assert_eq!(versions.last().unwrap().version(), "11");
+/// ```
+pub fn browser_versions(
+    name: &str,
+    opts: &Opts,
+) -> Result<impl ExactSizeIterator<Item = BrowserVersion> + DoubleEndedIterator, Error> {
+    let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
+        .ok_or_else(|| Error::BrowserNotFound(name.to_string()))?;
+    Ok(stat
+        .iter()
+        .map(move |detail| BrowserVersion { name, detail }))
+}
+
+/// Look up a version of the given browser.
+///
+/// Version aliases (like `7` for `ios_saf 7.0-7.1`), `tp` and
+/// missing trailing `.0` are handled as same as the query `<browser> <version>`.
+/// Unknown browser results in an error, while unknown version results in `None`.
+///
+/// ```
+/// use browserslist::{data, Opts};
+///
+/// let version = data::browser_version("ios", "7", &Opts::default())
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(version.name(), "ios_saf");
+/// assert_eq!(version.version(), "7.0-7.1");
+///
+/// assert!(data::browser_version("ie", "1", &Opts::default()).unwrap().is_none());
+/// ```
+pub fn browser_version(
+    name: &str,
+    version: &str,
+    opts: &Opts,
+) -> Result<Option<BrowserVersion>, Error> {
+    Ok(normalize_browser_version(name, version, opts)?
+        .and_then(|(name, version)| find_browser_version(name, &version, opts.mobile_to_desktop)))
+}
+
+/// Normalize browser name and version as the query `<browser> <version>` does.
+///
+/// The normalized version may not be in the version list,
+/// for example, `op_mob 59` is an alias of `op_mob 58`.
+pub(crate) fn normalize_browser_version(
+    name: &str,
+    version: &str,
+    opts: &Opts,
+) -> Result<Option<(&'static str, String)>, Error> {
+    let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
+        .ok_or_else(|| Error::BrowserNotFound(name.to_string()))?;
+    let version = if version.eq_ignore_ascii_case("tp") {
+        "TP"
+    } else {
+        version
+    };
+
+    if let Some(version) = normalize_version(name, stat, version) {
+        return Ok(Some((name, version.to_owned())));
+    }
+    let version = if version.contains('.') {
+        Cow::Borrowed(version.trim_end_matches(".0"))
+    } else {
+        let mut v = version.to_owned();
+        v.push_str(".0");
+        Cow::Owned(v)
+    };
+    Ok(normalize_version(name, stat, &version).map(|version| (name, version.to_owned())))
+}
+
+/// Find a version of the given browser by exact version string.
+pub(crate) fn find_browser_version(
+    name: &str,
+    version: &str,
+    mobile_to_desktop: bool,
+) -> Option<BrowserVersion> {
+    let (name, stat) = get_browser_stat(name, mobile_to_desktop)?;
+    stat.iter()
+        .find(|detail| detail.version() == version)
+        .map(|detail| BrowserVersion { name, detail })
+}
+
+/// List all Node.js versions, from oldest to newest.
+///
+/// ```
+/// use browserslist::data;
+///
+/// assert!(data::node_versions().any(|version| version == "16.0.0"));
+/// ```
+pub fn node_versions() -> impl ExactSizeIterator<Item = &'static str> + DoubleEndedIterator {
+    node::versions().iter().copied()
+}
+
+/// Get the V8 version of the given Node.js version.
+///
+/// ```
+/// use browserslist::data;
+///
+/// assert!(data::node_v8_version("16.0.0").unwrap().starts_with("9.0."));
+/// assert!(data::node_v8_version("3.0.0").is_none());
+/// ```
+pub fn node_v8_version(version: &str) -> Option<&'static str> {
+    node::v8_version(version)
+}
+
+/// List release schedules of all Node.js major versions, from oldest to newest.
+///
+/// ```
+/// use browserslist::data;
+///
+/// assert!(data::node_releases().any(|release| release.codename() == Some("Hydrogen")));
+/// ```
+pub fn node_releases() -> impl ExactSizeIterator<Item = NodeRelease> + DoubleEndedIterator {
+    node::releases()
+        .iter()
+        .map(|schedule| NodeRelease { schedule })
+}
+
+/// Get release schedule of a Node.js major version,
+/// by major version like `18` or LTS codename like `hydrogen` case-insensitively.
+///
+/// ```
+/// use browserslist::data;
+///
+/// let release = data::node_release("hydrogen").unwrap();
+/// assert_eq!(release.version(), "18");
+/// assert!(release.lts().unwrap() < release.end());
+/// assert!(release.latest_version().unwrap().starts_with("18."));
+///
+/// assert_eq!(data::node_release("18").unwrap().codename(), Some("Hydrogen"));
+/// assert!(data::node_release("argonaut").is_none());
+/// ```
+pub fn node_release(name: &str) -> Option<NodeRelease> {
+    node_releases().find(|release| {
+        release.version() == name
+            || release
+                .codename()
+                .is_some_and(|codename| codename.eq_ignore_ascii_case(name))
+    })
+}
+
+/// List all Electron versions with their corresponding Chromium versions,
+/// from oldest to newest.
+///
+/// ```
+/// use browserslist::data;
+///
+/// assert!(data::electron_versions().any(|(electron, chromium)| electron == 1.1 && chromium == "50"));
+/// ```
+pub fn electron_versions(
+) -> impl ExactSizeIterator<Item = (f32, &'static str)> + DoubleEndedIterator {
+    electron::versions()
+}
+
+/// List patch-level Electron versions with their full Chromium versions,
+/// from oldest to newest. Prereleases are not included.
+///
+/// ```
+/// use browserslist::data;
+///
+/// assert!(data::electron_full_versions().any(|(electron, chromium)| electron == "4.0.4" && chromium.starts_with("69.")));
+/// ```
+pub fn electron_full_versions(
+) -> impl ExactSizeIterator<Item = (&'static str, &'static str)> + DoubleEndedIterator {
+    electron::full_versions()
+}
+
+/// List Electron versions which ship the given Chromium major version,
+/// from oldest to newest.
+///
+/// To find Electron versions by a range of Chromium versions,
+/// filter [`electron_versions`] instead.
+///
+/// ```
+/// use browserslist::data;
+///
+/// assert!(data::chromium_to_electron("114").any(|electron| electron == "25.0"));
+/// assert_eq!(data::chromium_to_electron("1").count(), 0);
+///
+/// let mut electron = data::electron_versions()
+///     .filter(|(_, chromium)| chromium.parse::<u32>().is_ok_and(|chromium| chromium >= 114))
+///     .map(|(electron, _)| electron);
+/// assert!(electron.any(|electron| electron == 25.0));
+/// ```
+pub fn chromium_to_electron(chromium_version: &str) -> impl Iterator<Item = &'static str> + '_ {
+    electron::from_chromium(chromium_version).filter_map(electron::version_name)
+}
@@ -41,10 +41,18 @@ pub enum Error {
     /// Current environment doesn't support querying `extends`.
     UnsupportedExtends,
 
+    #[error("current environment for querying `node engines` is not supported")]
+    /// Current environment doesn't support querying `node engines`.
+    UnsupportedNodeEngines,
+
     #[error("unknown browser feature: '{0}'")]
     /// Unknown browser feature.
     UnknownBrowserFeature(String),
 
+    #[error("MDN feature '{0}' requires the `mdn` feature of browserslist-rs")]
+    /// MDN browser-compat-data isn't available since the `mdn` feature is disabled.
+    MdnFeatureDisabled(String),
+
     #[error("unknown region: '{0}'")]
     /// Unknown Can I Use region.
     UnknownRegion(String),
@@ -65,6 +73,14 @@ pub enum Error {
     /// Missing `browserslist` field in `package.json` file.
     MissingFieldInPkg(String),
 
+    #[error("missing 'engines.node' field in '{0}' file")]
+    /// Missing `engines.node` field in `package.json` file.
+    MissingEnginesInPkg(String),
+
+    #[error("no 'package.json' file found from '{0}'")]
+    /// No `package.json` file found in the given directory or its ancestors.
+    MissingPkg(String),
+
     #[error("duplicated: '{0}' directory contains both {1} and {2}.")]
     /// Duplicated configuration found.
     DuplicatedConfig(String, &'static str, &'static str),
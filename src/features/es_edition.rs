@@ -0,0 +1,276 @@
+use super::{caniuse_targets, get_support_flags};
+use crate::{opts::Opts, queries::Distrib};
+use browserslist_data::caniuse::features::{get_feature_stat, Feature};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// ECMAScript edition.
+pub enum EsEdition {
+    /// ECMAScript 5.
+    Es5,
+    /// ECMAScript 2015 (ES6).
+    Es2015,
+    /// ECMAScript 2016.
+    Es2016,
+    /// ECMAScript 2017.
+    Es2017,
+    /// ECMAScript 2018.
+    Es2018,
+    /// ECMAScript 2019.
+    Es2019,
+    /// ECMAScript 2020.
+    Es2020,
+    /// ECMAScript 2021.
+    Es2021,
+    /// ECMAScript 2022.
+    Es2022,
+    /// ECMAScript 2023.
+    Es2023,
+    /// ECMAScript 2024.
+    Es2024,
+}
+
+impl EsEdition {
+    const ALL: [EsEdition; 11] = [
+        Self::Es5,
+        Self::Es2015,
+        Self::Es2016,
+        Self::Es2017,
+        Self::Es2018,
+        Self::Es2019,
+        Self::Es2020,
+        Self::Es2021,
+        Self::Es2022,
+        Self::Es2023,
+        Self::Es2024,
+    ];
+
+    /// Return features which represent this edition.
+    ///
+    /// They're Can I Use feature names, or MDN browser-compat-data paths prefixed with `mdn:`
+    /// for features which Can I Use doesn't track.
+    /// MDN data is only available with the `mdn` feature of this crate.
+    pub fn features(self) -> &'static [&'static str] {
+        match self {
+            Self::Es5 => &["es5"],
+            Self::Es2015 => &[
+                "es6",
+                "arrow-functions",
+                "es6-class",
+                "es6-generators",
+                "const",
+                "let",
+                "promises",
+                "template-literals",
+            ],
+            Self::Es2016 => &["array-includes"],
+            Self::Es2017 => &["async-functions", "object-values", "pad-start-end"],
+            Self::Es2018 => &["promise-finally"],
+            Self::Es2019 => &["array-flat"],
+            Self::Es2020 => &[
+                "bigint",
+                "es6-module-dynamic-import",
+                "mdn:javascript.operators.nullish_coalescing",
+                "mdn:javascript.operators.optional_chaining",
+            ],
+            Self::Es2021 => &["mdn:javascript.builtins.String.replaceAll"],
+            Self::Es2022 => &[
+                "mdn:javascript.builtins.Array.at",
+                "mdn:javascript.classes.private_class_fields",
+            ],
+            Self::Es2023 => &["mdn:javascript.builtins.Array.findLast"],
+            Self::Es2024 => &["mdn:javascript.builtins.Promise.withResolvers"],
+        }
+    }
+}
+
+impl fmt::Display for EsEdition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Es5 => f.write_str("ES5"),
+            Self::Es2015 => f.write_str("ES2015"),
+            Self::Es2016 => f.write_str("ES2016"),
+            Self::Es2017 => f.write_str("ES2017"),
+            Self::Es2018 => f.write_str("ES2018"),
+            Self::Es2019 => f.write_str("ES2019"),
+            Self::Es2020 => f.write_str("ES2020"),
+            Self::Es2021 => f.write_str("ES2021"),
+            Self::Es2022 => f.write_str("ES2022"),
+            Self::Es2023 => f.write_str("ES2023"),
+            Self::Es2024 => f.write_str("ES2024"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Result of [`es_edition`].
+pub struct EsEditionReport {
+    /// The highest edition fully supported by all targets,
+    /// or `None` if even ES5 isn't fully supported.
+    pub edition: Option<EsEdition>,
+    /// Targets and features which block the next edition.
+    /// It's empty if the highest known edition is supported.
+    pub blockers: Vec<(Distrib, &'static str)>,
+    /// The next edition which can't be checked
+    /// because all of its features are missing in the bundled data.
+    pub missing: Option<EsEdition>,
+}
+
+/// Infer the highest ECMAScript edition fully supported by all the given targets.
+///
+/// Targets which aren't in Can I Use data are ignored,
+/// while Node.js is checked with data of Chrome which ships the same V8 version.
+/// Inference stops at the first edition whose features are all missing in the bundled data,
+/// which is reported as [`EsEditionReport::missing`].
+/// Features of ES2021 and later are only available with the `mdn` feature.
+///
+/// ```
+/// use browserslist::{features::{es_edition, EsEdition}, resolve, Opts};
+///
+/// let opts = Opts::default();
+///
+/// let report = es_edition(&resolve(["ie 11"], &opts).unwrap(), &opts);
+/// assert_eq!(report.edition, Some(EsEdition::Es5));
+/// assert!(report.blockers.iter().any(|(distrib, _)| distrib.name() == "ie"));
+/// ```
+pub fn es_edition<'a, I>(distribs: I, opts: &Opts) -> EsEditionReport
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    let distribs = caniuse_targets(distribs);
+    let mut edition = None;
+
+    for next in EsEdition::ALL {
+        let features = next
+            .features()
+            .iter()
+            .filter_map(|name| EditionFeature::get(name).map(|feature| (*name, feature)))
+            .collect::<Vec<_>>();
+        if features.is_empty() {
+            return EsEditionReport {
+                edition,
+                blockers: vec![],
+                missing: Some(next),
+            };
+        }
+
+        let blockers = features
+            .iter()
+            .flat_map(|(name, feature)| {
+                distribs
+                    .iter()
+                    .filter(|distrib| !feature.is_supported(distrib, opts))
+                    .map(move |distrib| ((*distrib).clone(), *name))
+            })
+            .collect::<Vec<_>>();
+        if !blockers.is_empty() {
+            return EsEditionReport {
+                edition,
+                blockers,
+                missing: None,
+            };
+        }
+        edition = Some(next);
+    }
+
+    EsEditionReport {
+        edition,
+        blockers: vec![],
+        missing: None,
+    }
+}
+
+/// Feature of an edition, from either Can I Use or MDN data.
+enum EditionFeature {
+    Caniuse(Feature),
+    #[cfg(feature = "mdn")]
+    Mdn(browserslist_data::mdn::MdnFeature),
+}
+
+impl EditionFeature {
+    fn get(name: &str) -> Option<Self> {
+        match name.strip_prefix("mdn:") {
+            #[cfg(feature = "mdn")]
+            Some(path) => browserslist_data::mdn::get_mdn_feature(path).map(Self::Mdn),
+            #[cfg(not(feature = "mdn"))]
+            Some(_) => None,
+            None => get_feature_stat(name).map(Self::Caniuse),
+        }
+    }
+
+    fn is_supported(&self, distrib: &Distrib, opts: &Opts) -> bool {
+        let flags = match self {
+            Self::Caniuse(feature) => get_support_flags(feature, distrib, opts),
+            #[cfg(feature = "mdn")]
+            Self::Mdn(feature) => mdn_support_flags(feature, distrib),
+        };
+        flags.is_some_and(|flags| flags.matches(false, false, opts))
+    }
+}
+
+/// Get support flags of a MDN feature for the given target.
+/// Node.js and Electron are checked with their Chrome versions like Can I Use features.
+#[cfg(feature = "mdn")]
+fn mdn_support_flags(
+    feature: &browserslist_data::mdn::MdnFeature,
+    distrib: &Distrib,
+) -> Option<super::SupportFlags> {
+    let (name, version) = if distrib.name() == "node" {
+        let chromium_version = browserslist_data::node::chromium_version(distrib.version())?;
+        ("chrome", chromium_version.to_string())
+    } else if let Some(chrome) = distrib.electron_to_chrome() {
+        ("chrome", chrome.version().to_string())
+    } else {
+        (distrib.name(), distrib.version().to_string())
+    };
+    let statements = feature.get(name)?;
+    Some(super::mdn::mdn_support_flags(statements, &version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve;
+
+    #[test]
+    fn features_exist() {
+        // every edition must be backed by the bundled data,
+        // otherwise inference silently stops there
+        for edition in EsEdition::ALL {
+            for name in edition.features() {
+                if cfg!(feature = "mdn") || !name.starts_with("mdn:") {
+                    assert!(
+                        EditionFeature::get(name).is_some(),
+                        "feature {name} of {edition} is missing"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn missing() {
+        let opts = Opts::default();
+        let report = es_edition(&resolve(["last 1 chrome version"], &opts).unwrap(), &opts);
+        if cfg!(feature = "mdn") {
+            assert_eq!(report.edition, Some(EsEdition::Es2024));
+            assert_eq!(report.missing, None);
+        } else {
+            assert_eq!(report.edition, Some(EsEdition::Es2020));
+            assert_eq!(report.missing, Some(EsEdition::Es2021));
+        }
+        assert!(report.blockers.is_empty());
+    }
+
+    #[test]
+    fn blockers() {
+        let opts = Opts::default();
+        let report = es_edition(&resolve(["ie 11", "chrome 100"], &opts).unwrap(), &opts);
+        assert_eq!(report.edition, Some(EsEdition::Es5));
+        assert!(report
+            .blockers
+            .iter()
+            .all(|(distrib, _)| distrib.name() == "ie"));
+        assert_eq!(report.missing, None);
+    }
+}
@@ -0,0 +1,112 @@
+use super::{caniuse_targets, get_support_flags, SupportStatus};
+use crate::{opts::Opts, queries::Distrib};
+use browserslist_data::caniuse::features::{get_feature_index, iter_features};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// Can I Use features supported by all of the given targets.
+///
+/// Support status is precomputed into bitsets, so looking up a feature is cheap.
+/// Targets which aren't in Can I Use data are ignored,
+/// while Node.js is checked with data of Chrome which ships the same V8 version.
+///
+/// ```
+/// use browserslist::{features::FeatureSet, resolve, Opts};
+///
+/// let opts = Opts::default();
+/// let features = FeatureSet::new(&resolve(["firefox 93"], &opts).unwrap(), &opts);
+///
+/// assert!(features.is_fully_supported("arrow-functions"));
+/// assert!(features.fully_supported().any(|name| name == "arrow-functions"));
+/// assert!(!features.is_fully_supported("xxxyyyzzz"));
+/// ```
+pub struct FeatureSet {
+    full: Vec<u64>,
+    partial: Vec<u64>,
+}
+
+impl FeatureSet {
+    /// Compute features supported by all of the given targets.
+    pub fn new<'a, I>(distribs: I, opts: &Opts) -> Self
+    where
+        I: IntoIterator<Item = &'a Distrib>,
+    {
+        let distribs = caniuse_targets(distribs);
+        let features = iter_features();
+        let len = features.len().div_ceil(64);
+        let mut set = Self {
+            full: vec![0; len],
+            partial: vec![0; len],
+        };
+
+        for (index, (_, feature)) in features.enumerate() {
+            let (full, partial) = distribs
+                .iter()
+                .fold(
+                    (true, true),
+                    |(full, partial), distrib| match SupportStatus::from_flags(
+                        get_support_flags(&feature, distrib, opts),
+                        opts,
+                    ) {
+                        SupportStatus::Full => (full, partial),
+                        SupportStatus::Partial => (false, partial),
+                        _ => (false, false),
+                    },
+                );
+            if full {
+                set.full[index / 64] |= 1 << (index % 64);
+            } else if partial {
+                set.partial[index / 64] |= 1 << (index % 64);
+            }
+        }
+
+        set
+    }
+
+    /// Return `true` if the feature is fully supported by all targets.
+    pub fn is_fully_supported(&self, feature: &str) -> bool {
+        get_feature_index(feature).is_some_and(|index| contains(&self.full, index))
+    }
+
+    /// Return `true` if the feature is supported by all targets,
+    /// but some of them only support it partially.
+    pub fn is_partially_supported(&self, feature: &str) -> bool {
+        get_feature_index(feature).is_some_and(|index| contains(&self.partial, index))
+    }
+
+    /// Iterate over names of features which are fully supported by all targets.
+    pub fn fully_supported(&self) -> impl Iterator<Item = &'static str> + '_ {
+        iter_features()
+            .enumerate()
+            .filter(|(index, _)| contains(&self.full, *index))
+            .map(|(_, (name, _))| name)
+    }
+
+    /// Iterate over names of features which are supported by all targets,
+    /// but some of them only support it partially.
+    pub fn partially_supported(&self) -> impl Iterator<Item = &'static str> + '_ {
+        iter_features()
+            .enumerate()
+            .filter(|(index, _)| contains(&self.partial, *index))
+            .map(|(_, (name, _))| name)
+    }
+}
+
+fn contains(bits: &[u64], index: usize) -> bool {
+    bits[index / 64] & (1 << (index % 64)) != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve;
+
+    #[test]
+    fn node() {
+        let opts = Opts::default();
+        let features = FeatureSet::new(&resolve(["node 18.0.0"], &opts).unwrap(), &opts);
+        assert!(features.is_fully_supported("arrow-functions"));
+        assert!(!features.is_fully_supported("css-grid"));
+        assert!(!features.is_partially_supported("css-grid"));
+        assert!(!features.is_fully_supported("rtcpeerconnection"));
+    }
+}
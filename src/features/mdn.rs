@@ -0,0 +1,35 @@
+use super::SupportFlags;
+use crate::semver::compare;
+use browserslist_data::{caniuse::features::flags, mdn::SupportStatement};
+use std::cmp::Ordering;
+
+/// Get support flags of a browser version from MDN support statements,
+/// preferring full support over partial support, and unprefixed over prefixed.
+pub(crate) fn mdn_support_flags(statements: &[SupportStatement], version: &str) -> SupportFlags {
+    statements
+        .iter()
+        .filter(|statement| covers(statement, version))
+        .map(|statement| SupportFlags {
+            flags: statement.flags,
+            notes: Default::default(),
+        })
+        .max_by_key(|flags| {
+            (
+                flags.is_supported(),
+                flags.is_partial(),
+                !flags.needs_prefix(),
+            )
+        })
+        .unwrap_or(SupportFlags {
+            flags: flags::N,
+            notes: Default::default(),
+        })
+}
+
+fn covers(statement: &SupportStatement, version: &str) -> bool {
+    // non-numeric version like Safari TP is newer than any released version
+    let is_preview = version.eq_ignore_ascii_case("tp");
+    (is_preview || compare(version, statement.version_added) != Ordering::Less)
+        && (statement.version_removed.is_empty()
+            || !is_preview && compare(version, statement.version_removed) == Ordering::Less)
+}
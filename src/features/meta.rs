@@ -0,0 +1,109 @@
+use crate::error::Error;
+use browserslist_data::caniuse::features::{get_feature_meta, iter_feature_meta, FeatureMeta};
+
+#[derive(Clone, Copy, Debug)]
+/// Metadata of a Can I Use feature.
+///
+/// ```
+/// use browserslist::features::feature_info;
+///
+/// let info = feature_info("css-grid").unwrap();
+/// assert_eq!(info.name(), "css-grid");
+/// assert!(info.categories().contains(&"CSS"));
+/// ```
+pub struct FeatureInfo {
+    name: &'static str,
+    meta: &'static FeatureMeta,
+}
+
+impl FeatureInfo {
+    #[inline]
+    /// Return feature name used in `supports` query, such as `flexbox-gap`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline]
+    /// Return human-readable title.
+    pub fn title(&self) -> &'static str {
+        self.meta.title
+    }
+
+    #[inline]
+    /// Return description.
+    pub fn description(&self) -> &'static str {
+        self.meta.description
+    }
+
+    #[inline]
+    /// Return URL of the specification.
+    pub fn spec(&self) -> &'static str {
+        self.meta.spec
+    }
+
+    #[inline]
+    /// Return status of the specification,
+    /// such as `ls` (living standard), `rec` (W3C recommendation) and `wd` (working draft).
+    pub fn status(&self) -> &'static str {
+        self.meta.status
+    }
+
+    #[inline]
+    /// Return categories, such as `CSS`, `JS API` and `HTML5`.
+    pub fn categories(&self) -> &'static [&'static str] {
+        self.meta.categories
+    }
+
+    /// Iterate over keywords of this feature.
+    pub fn keywords(&self) -> impl Iterator<Item = &'static str> {
+        self.meta
+            .keywords
+            .split(',')
+            .map(str::trim)
+            .filter(|keyword| !keyword.is_empty())
+    }
+
+    /// Check if name, title or keywords contain the given lowercase keyword.
+    fn matches(&self, keyword: &str) -> bool {
+        self.name.contains(keyword)
+            || self.title().to_ascii_lowercase().contains(keyword)
+            || self
+                .keywords()
+                .any(|k| k.to_ascii_lowercase().contains(keyword))
+    }
+}
+
+/// Get metadata of a Can I Use feature.
+///
+/// ```
+/// use browserslist::{features::feature_info, Error};
+///
+/// assert!(feature_info("flexbox-gap").is_ok());
+/// assert_eq!(
+///     feature_info("flexbox-gaps").unwrap_err(),
+///     Error::UnknownBrowserFeature(String::from("flexbox-gaps"))
+/// );
+/// ```
+pub fn feature_info(name: &str) -> Result<FeatureInfo, Error> {
+    get_feature_meta(name)
+        .map(|(name, meta)| FeatureInfo { name, meta })
+        .ok_or_else(|| Error::UnknownBrowserFeature(name.to_string()))
+}
+
+/// Iterate over all Can I Use features, sorted by name.
+pub fn all_features() -> impl ExactSizeIterator<Item = FeatureInfo> {
+    iter_feature_meta().map(|(name, meta)| FeatureInfo { name, meta })
+}
+
+/// Search Can I Use features whose name, title or keywords contain the given keyword,
+/// case-insensitively.
+///
+/// ```
+/// use browserslist::features::search_features;
+///
+/// assert!(search_features("GRID").any(|info| info.name() == "css-grid"));
+/// ```
+pub fn search_features(keyword: &str) -> impl Iterator<Item = FeatureInfo> {
+    let keyword = keyword.to_ascii_lowercase();
+    all_features().filter(move |info| info.matches(&keyword))
+}
@@ -0,0 +1,304 @@
+//! Check support of Can I Use features against resolved distribs.
+
+use crate::{error::Error, opts::Opts, queries::Distrib};
+use browserslist_data::{
+    caniuse::{
+        features::{flags, get_feature_stat, Feature, Notes, VersionList},
+        get_browser_stat, to_desktop_name,
+    },
+    node,
+};
+
+mod es_edition;
+mod feature_set;
+#[cfg(feature = "mdn")]
+pub(crate) mod mdn;
+mod meta;
+mod prefixes;
+mod usage;
+
+pub use es_edition::{es_edition, EsEdition, EsEditionReport};
+pub use feature_set::FeatureSet;
+pub use meta::{all_features, feature_info, search_features, FeatureInfo};
+pub use prefixes::required_prefixes;
+pub use usage::{feature_usage, FeatureUsage, UsageStats};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Support flags of a feature in Can I Use data, such as `y`, `a x` and `n d #1`.
+///
+/// ```
+/// use browserslist::{features::support_flags, resolve, Opts};
+///
+/// let opts = Opts::default();
+/// let distrib = &resolve(["firefox 93"], &opts).unwrap()[0];
+///
+/// let flags = support_flags("arrow-functions", distrib, &opts).unwrap().unwrap();
+/// assert!(flags.is_supported());
+/// assert!(!flags.needs_prefix());
+/// ```
+pub struct SupportFlags {
+    flags: u8,
+    notes: Notes,
+}
+
+impl SupportFlags {
+    #[inline]
+    /// Return `true` if it's supported (`y`).
+    pub fn is_supported(&self) -> bool {
+        self.flags & flags::Y != 0
+    }
+
+    #[inline]
+    /// Return `true` if it's partially supported (`a`).
+    pub fn is_partial(&self) -> bool {
+        self.flags & flags::A != 0
+    }
+
+    #[inline]
+    /// Return `true` if it's not supported (`n`).
+    pub fn is_unsupported(&self) -> bool {
+        self.flags & flags::N != 0
+    }
+
+    #[inline]
+    /// Return `true` if it requires vendor prefix (`x`).
+    pub fn needs_prefix(&self) -> bool {
+        self.flags & flags::X != 0
+    }
+
+    #[inline]
+    /// Return `true` if it's disabled by default (`d`).
+    pub fn is_disabled_by_default(&self) -> bool {
+        self.flags & flags::D != 0
+    }
+
+    #[inline]
+    /// Return `true` if it can be supported with polyfill (`p`).
+    pub fn is_polyfill(&self) -> bool {
+        self.flags & flags::P != 0
+    }
+
+    #[inline]
+    /// Return `true` if support is unknown (`u`).
+    pub fn is_unknown(&self) -> bool {
+        self.flags & flags::U != 0
+    }
+
+    /// Iterate over numbers of notes referenced by `#n`.
+    ///
+    /// ```
+    /// use browserslist::{features::support_flags, resolve, Opts};
+    ///
+    /// let opts = Opts::default();
+    /// let distrib = &resolve(["chrome 100"], &opts).unwrap()[0];
+    ///
+    /// let flags = support_flags("font-smooth", distrib, &opts).unwrap().unwrap();
+    /// assert_eq!(flags.notes().collect::<Vec<_>>(), [1]);
+    /// ```
+    pub fn notes(&self) -> impl Iterator<Item = u32> {
+        self.notes.iter()
+    }
+
+    /// Return `true` if it counts as supported,
+    /// considering partial support, vendor prefix and unknown support.
+    pub(crate) fn matches(&self, include_partial: bool, without_prefix: bool, opts: &Opts) -> bool {
+        (self.is_supported()
+            || include_partial && self.is_partial()
+            || opts.unknown_as_supported && self.is_unknown())
+            && !(without_prefix && self.needs_prefix())
+    }
+
+    pub(crate) fn from_version_list(versions: VersionList, version: &str) -> Option<Self> {
+        Some(Self {
+            flags: versions.get(version)?,
+            notes: versions.get_notes(version)?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Support status of a feature.
+pub enum SupportStatus {
+    /// Fully supported.
+    Full,
+    /// Partially supported.
+    Partial,
+    /// Not supported.
+    Unsupported,
+    /// There's no support data, for example, old Node.js or unknown versions.
+    Unknown,
+}
+
+impl SupportStatus {
+    fn from_flags(flags: Option<SupportFlags>, opts: &Opts) -> Self {
+        match flags {
+            Some(flags) if flags.matches(false, false, opts) => Self::Full,
+            Some(flags) if flags.is_partial() => Self::Partial,
+            Some(flags) if flags.is_unknown() => Self::Unknown,
+            Some(_) => Self::Unsupported,
+            None => Self::Unknown,
+        }
+    }
+}
+
+/// Check support status of a Can I Use feature for each of the given targets.
+///
+/// The options `mobile_to_desktop` and `unknown_as_supported` are respected
+/// as same as the `supports` query.
+/// Node.js is checked with data of Chrome which ships the same V8 version,
+/// so it's only meaningful for JavaScript language features.
+///
+/// ```
+/// use browserslist::{features::{check_feature, SupportStatus}, resolve, Opts};
+///
+/// let opts = Opts::default();
+/// let distribs = resolve(["ie 11", "firefox 93", "node 16.0", "node 0.10"], &opts).unwrap();
+///
+/// let result = check_feature("arrow-functions", &distribs, &opts).unwrap();
+/// assert_eq!(
+///     result.iter().map(|(d, status)| (d.name(), *status)).collect::<Vec<_>>(),
+///     vec![
+///         ("firefox", SupportStatus::Full),
+///         ("ie", SupportStatus::Unsupported),
+///         ("node", SupportStatus::Full),
+///         ("node", SupportStatus::Unknown),
+///     ]
+/// );
+/// ```
+pub fn check_feature<'a, I>(
+    feature: &str,
+    distribs: I,
+    opts: &Opts,
+) -> Result<Vec<(&'a Distrib, SupportStatus)>, Error>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    let stat =
+        get_feature_stat(feature).ok_or_else(|| Error::UnknownBrowserFeature(feature.into()))?;
+    Ok(distribs
+        .into_iter()
+        .map(|distrib| {
+            let flags = get_support_flags(&stat, distrib, opts);
+            (distrib, SupportStatus::from_flags(flags, opts))
+        })
+        .collect())
+}
+
+/// Get support flags of a Can I Use feature for the given target,
+/// or `None` if there's no support data.
+///
+/// The option `mobile_to_desktop` is respected as same as the `supports` query.
+pub fn support_flags(
+    feature: &str,
+    distrib: &Distrib,
+    opts: &Opts,
+) -> Result<Option<SupportFlags>, Error> {
+    let stat =
+        get_feature_stat(feature).ok_or_else(|| Error::UnknownBrowserFeature(feature.into()))?;
+    Ok(get_support_flags(&stat, distrib, opts))
+}
+
+/// Get support flags of the given distrib for a feature.
+///
+/// With `mobile_to_desktop`, mobile browsers fall back to data of their desktop browsers
+/// when the version is missing.
+/// Node.js versions use data of Chrome versions which ship the same V8 version,
+/// but only for JavaScript features, since browser APIs and CSS don't apply to Node.js.
+/// Electron versions use data of their Chromium versions.
+pub(crate) fn get_support_flags(
+    feature: &Feature,
+    distrib: &Distrib,
+    opts: &Opts,
+) -> Option<SupportFlags> {
+    if distrib.name() == "node" {
+        if !feature.meta().categories.contains(&"JS") {
+            return None;
+        }
+        let chromium_version = node::chromium_version(distrib.version())?;
+        get_version_support_flags(feature, "chrome", &chromium_version.to_string(), opts)
+    } else if let Some(chrome) = distrib.electron_to_chrome() {
+        get_version_support_flags(feature, "chrome", chrome.version(), opts)
+    } else {
+        get_version_support_flags(feature, distrib.name(), distrib.version(), opts)
+    }
+}
+
+/// Same as [`get_support_flags`], but for the given browser name and version.
+fn get_version_support_flags(
+    feature: &Feature,
+    name: &str,
+    version: &str,
+    opts: &Opts,
+) -> Option<SupportFlags> {
+    let versions = feature.get(name)?;
+    SupportFlags::from_version_list(versions, version).or_else(|| {
+        desktop_fallback(name, versions, true, false, opts)
+            .and_then(|desktop_name| feature.get(desktop_name))
+            .and_then(|versions| SupportFlags::from_version_list(versions, version))
+    })
+}
+
+/// Get the desktop browser whose data is used for versions of the given mobile browser
+/// missing in Can I Use data, when `mobile_to_desktop` is enabled.
+///
+/// It falls back only if the latest released version of the mobile browser
+/// counts as supported, so the desktop data won't contradict the mobile data.
+pub(crate) fn desktop_fallback(
+    name: &str,
+    versions: VersionList,
+    include_partial: bool,
+    without_prefix: bool,
+    opts: &Opts,
+) -> Option<&'static str> {
+    let desktop_name = opts
+        .mobile_to_desktop
+        .then(|| to_desktop_name(name))
+        .flatten()?;
+    let (_, browser_stat) = get_browser_stat(name, opts.mobile_to_desktop)?;
+    browser_stat
+        .iter()
+        .filter(|version| version.released)
+        .filter_map(|latest_version| {
+            SupportFlags::from_version_list(versions, latest_version.version())
+        })
+        .next_back()
+        .is_some_and(|flags| flags.matches(include_partial, without_prefix, opts))
+        .then_some(desktop_name)
+}
+
+/// Collect targets which are in Can I Use data,
+/// including Node.js versions whose V8 version is known to ship with Chrome.
+pub(crate) fn caniuse_targets<'a, I>(distribs: I) -> Vec<&'a Distrib>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    distribs
+        .into_iter()
+        .filter(|distrib| {
+            if distrib.name() == "node" {
+                node::chromium_version(distrib.version()).is_some()
+            } else {
+                get_browser_stat(distrib.name(), false).is_some()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve;
+    use test_case::test_case;
+
+    #[test_case("arrow-functions", SupportStatus::Full; "javascript")]
+    #[test_case("css-grid", SupportStatus::Unknown; "css")]
+    #[test_case("rtcpeerconnection", SupportStatus::Unknown; "browser api")]
+    fn node(feature: &str, status: SupportStatus) {
+        let opts = Opts::default();
+        let distribs = resolve(["node 18.0.0"], &opts).unwrap();
+        assert_eq!(
+            check_feature(feature, &distribs, &opts).unwrap()[0].1,
+            status
+        );
+    }
+}
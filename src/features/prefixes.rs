@@ -0,0 +1,52 @@
+use super::get_support_flags;
+use crate::{error::Error, opts::Opts, queries::Distrib};
+use browserslist_data::caniuse::{features::get_feature_stat, get_browser_prefix};
+use std::collections::BTreeSet;
+
+/// Collect vendor prefixes (like `-webkit-` and `-moz-`) which are still required
+/// by the given targets to use a Can I Use feature.
+///
+/// Only targets which support the feature fully or partially with prefix (`x`) are considered.
+/// Prefix of each target is decided by its browser and version,
+/// for example, Opera before 15 requires `-o-` while later versions require `-webkit-`.
+/// The option `mobile_to_desktop` is respected as same as the `supports` query.
+///
+/// ```
+/// use browserslist::{features::required_prefixes, resolve, Opts};
+///
+/// let opts = Opts::default();
+/// let distribs = resolve(["opera 12.1", "safari 15.4", "firefox 93"], &opts).unwrap();
+///
+/// assert_eq!(
+///     required_prefixes("css-grid", &distribs, &opts).unwrap().into_iter().collect::<Vec<_>>(),
+///     vec!["-o-", "-webkit-"]
+/// );
+/// ```
+pub fn required_prefixes<'a, I>(
+    feature: &str,
+    distribs: I,
+    opts: &Opts,
+) -> Result<BTreeSet<&'static str>, Error>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    let stat =
+        get_feature_stat(feature).ok_or_else(|| Error::UnknownBrowserFeature(feature.into()))?;
+    Ok(distribs
+        .into_iter()
+        .filter(|distrib| {
+            get_support_flags(&stat, distrib, opts).is_some_and(|flags| {
+                flags.needs_prefix() && (flags.is_supported() || flags.is_partial())
+            })
+        })
+        .filter_map(|distrib| get_browser_prefix(distrib.name(), distrib.version()))
+        .map(|prefix| match prefix {
+            "webkit" => "-webkit-",
+            "moz" => "-moz-",
+            "ms" => "-ms-",
+            "o" => "-o-",
+            _ => "",
+        })
+        .filter(|prefix| !prefix.is_empty())
+        .collect())
+}
@@ -0,0 +1,89 @@
+use super::{get_version_support_flags, SupportStatus};
+use crate::{error::Error, opts::Opts};
+use browserslist_data::caniuse::{self, features::get_feature_stat, region::get_usage_by_region};
+
+#[derive(Clone, Copy, Debug)]
+/// Source of browser usage statistics.
+pub enum UsageStats<'a> {
+    /// Global usage in Can I Use data.
+    Global,
+    /// Usage of a country or a region in Can I Use data, such as `DE` and `alt-eu`.
+    Region(&'a str),
+    /// Custom usage as a list of browser name, version and usage in percentage.
+    Custom(&'a [(&'a str, &'a str, f32)]),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Share of users in percentage, grouped by support status of a feature.
+pub struct FeatureUsage {
+    /// Users with full support.
+    pub full: f32,
+    /// Users with partial support.
+    pub partial: f32,
+    /// Users without support.
+    pub unsupported: f32,
+    /// Users of browser versions without support data.
+    pub unknown: f32,
+}
+
+impl FeatureUsage {
+    #[inline]
+    /// Return share of users with full or partial support,
+    /// which is the total shown on caniuse.com.
+    pub fn supported(&self) -> f32 {
+        self.full + self.partial
+    }
+}
+
+/// Calculate share of users who can use a Can I Use feature,
+/// weighted by usage statistics.
+///
+/// The options `mobile_to_desktop` and `unknown_as_supported` are respected
+/// as same as the `supports` query.
+///
+/// ```
+/// use browserslist::{features::{feature_usage, UsageStats}, Opts};
+///
+/// let usage = feature_usage("css-grid", UsageStats::Region("us"), &Opts::default()).unwrap();
+/// assert!(usage.full > 0.0);
+///
+/// let stats = [("ie", "11", 1.0), ("firefox", "93", 3.0)];
+/// let usage = feature_usage("arrow-functions", UsageStats::Custom(&stats), &Opts::default()).unwrap();
+/// assert_eq!(usage.full, 3.0);
+/// assert_eq!(usage.unsupported, 1.0);
+/// ```
+pub fn feature_usage(feature: &str, stats: UsageStats, opts: &Opts) -> Result<FeatureUsage, Error> {
+    let stat =
+        get_feature_stat(feature).ok_or_else(|| Error::UnknownBrowserFeature(feature.into()))?;
+
+    let sum = |usages: &mut dyn Iterator<Item = (&str, &str, f32)>| {
+        usages.fold(
+            FeatureUsage::default(),
+            |mut sum, (name, version, usage)| {
+                let flags = get_version_support_flags(&stat, name, version, opts);
+                match SupportStatus::from_flags(flags, opts) {
+                    SupportStatus::Full => sum.full += usage,
+                    SupportStatus::Partial => sum.partial += usage,
+                    SupportStatus::Unsupported => sum.unsupported += usage,
+                    SupportStatus::Unknown => sum.unknown += usage,
+                }
+                sum
+            },
+        )
+    };
+
+    match stats {
+        UsageStats::Global => Ok(sum(&mut caniuse::iter_global_usage())),
+        UsageStats::Region(region) => {
+            let normalized_region = if region.len() == 2 {
+                region.to_uppercase()
+            } else {
+                region.to_lowercase()
+            };
+            let region_data = get_usage_by_region(&normalized_region)
+                .ok_or_else(|| Error::UnknownRegion(region.to_string()))?;
+            Ok(sum(&mut region_data.iter()))
+        }
+        UsageStats::Custom(usages) => Ok(sum(&mut usages.iter().copied())),
+    }
+}
@@ -59,16 +59,25 @@
 //! so you will receive an error when querying `current node` in those environments.
 
 use parser::parse_browserslist_query;
-use std::cmp::Ordering;
-pub use {error::Error, opts::Opts, queries::Distrib};
+pub use {
+    error::Error,
+    opts::Opts,
+    queries::Distrib,
+    semver::{DistribVersion, Version},
+    set::DistribSet,
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 mod config;
+pub mod data;
 mod error;
+pub mod features;
 mod opts;
 mod parser;
 mod queries;
 mod semver;
+mod set;
+pub mod targets;
 #[cfg(test)]
 mod test;
 
@@ -89,6 +98,27 @@ mod test;
 /// assert_eq!(distribs[1].version(), "5.5");
 /// ```
 pub fn resolve<I, S>(queries: I, opts: &Opts) -> Result<Vec<Distrib>, Error>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    resolve_set(queries, opts).map(Vec::from)
+}
+
+/// Resolve browserslist queries into a [`DistribSet`].
+///
+/// This works as same as [`resolve`], but the result supports set operations.
+///
+/// ```
+/// use browserslist::{Opts, resolve_set};
+///
+/// let distribs = resolve_set(["ie <= 6"], &Opts::default()).unwrap();
+/// assert_eq!(
+///     distribs.iter().map(|d| d.to_string()).collect::<Vec<_>>(),
+///     vec!["ie 6", "ie 5.5"]
+/// );
+/// ```
+pub fn resolve_set<I, S>(queries: I, opts: &Opts) -> Result<DistribSet, Error>
 where
     S: AsRef<str>,
     I: IntoIterator<Item = S>,
@@ -104,41 +134,28 @@ where
             s
         });
 
-    let mut distribs = parse_browserslist_query(&query)?
+    parse_browserslist_query(&query)?
         .1
         .into_iter()
         .enumerate()
-        .try_fold(vec![], |mut distribs, (i, current)| {
+        .try_fold(DistribSet::new(), |mut distribs, (i, current)| {
             if i == 0 && current.negated {
                 return Err(Error::NotAtFirst(current.raw.to_string()));
             }
 
-            let mut dist = queries::query(current.atom, opts)?;
+            let dist = queries::query(current.atom, opts)?;
             if current.negated {
+                let dist = DistribSet::from_iter(dist);
                 distribs.retain(|distrib| !dist.contains(distrib));
             } else if current.is_and {
+                let dist = DistribSet::from_iter(dist);
                 distribs.retain(|distrib| dist.contains(distrib));
             } else {
-                distribs.append(&mut dist);
+                distribs.extend(dist);
             }
 
-            Ok::<_, Error>(distribs)
-        })?;
-
-    distribs.sort_by(|a, b| match a.name().cmp(b.name()) {
-        Ordering::Equal => {
-            let version_a = a.version().split('-').next().unwrap();
-            let version_b = b.version().split('-').next().unwrap();
-            version_b
-                .parse::<semver::Version>()
-                .unwrap_or_default()
-                .cmp(&version_a.parse().unwrap_or_default())
-        }
-        ord => ord,
-    });
-    distribs.dedup();
-
-    Ok(distribs)
+            Ok(distribs)
+        })
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -25,4 +25,22 @@ pub struct Opts {
 
     /// Disable security checks for `extends` query.
     pub dangerous_extend: bool,
+
+    /// Count versions whose support is unknown in Can I Use data as supported
+    /// for `supports` query and feature checks.
+    pub unknown_as_supported: bool,
+
+    /// Return results of Electron queries as `electron` versions,
+    /// instead of converting them to `chrome` versions.
+    /// Known patch-level versions like `electron 28.2.3` are kept as they are.
+    pub keep_electron: bool,
+
+    /// Include Node.js versions in results of `supports` query for JavaScript features,
+    /// which are checked with Chrome versions shipping the same V8 version.
+    pub node_in_supports: bool,
+
+    /// Resolve `current node` query from the Node.js version which the project pins
+    /// in `.nvmrc`, `.node-version`, `.tool-versions` or `engines.node` of `package.json`,
+    /// before falling back to running `node -v`.
+    pub current_node_from_project: bool,
 }
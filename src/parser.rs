@@ -9,6 +9,11 @@ use nom::{
     IResult,
 };
 
+use crate::{
+    data::{BrowserType, Engine},
+    semver::Version,
+};
+
 type PResult<'a, Output> = IResult<&'a str, Output>;
 
 #[derive(Debug, Clone)]
@@ -34,18 +39,25 @@ pub enum QueryAtom<'a> {
         coverage: f32,
         stats: Stats<'a>,
     },
-    Supports(&'a str, Option<SupportKind>),
+    Supports(&'a str, Option<SupportKind>, bool),
     Electron(VersionRange<'a>),
     Node(VersionRange<'a>),
     Browser(&'a str, VersionRange<'a>),
-    FirefoxESR,
+    FirefoxESR(Option<VersionRange<'a>>),
     OperaMini,
     CurrentNode,
     MaintainedNode,
+    NodeLts,
+    LastNodeLts(u16),
+    NodeCodename(&'a str),
+    NodeEngines,
     Phantom(bool),
     BrowserslistConfig,
     Defaults,
     Dead,
+    Platform(BrowserType),
+    Engine(Engine),
+    EngineVersion(Engine, VersionRange<'a>),
     Extends(&'a str),
     Unknown(&'a str), // unnecessary, but for better error report
 }
@@ -72,14 +84,11 @@ fn parse_last(input: &str) -> PResult<QueryAtom> {
             terminated(tag_no_case("last"), space1),
             terminated(u16, space1),
             opt(terminated(
-                verify(
-                    take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                    |s: &str| {
-                        !s.eq_ignore_ascii_case("version")
-                            && !s.eq_ignore_ascii_case("versions")
-                            && !s.eq_ignore_ascii_case("major")
-                    },
-                ),
+                verify(parse_browser_name, |s: &str| {
+                    !s.eq_ignore_ascii_case("version")
+                        && !s.eq_ignore_ascii_case("versions")
+                        && !s.eq_ignore_ascii_case("major")
+                }),
                 space1,
             )),
             opt(terminated(tag_no_case("major"), space1)),
@@ -107,10 +116,7 @@ fn parse_unreleased(input: &str) -> PResult<QueryAtom> {
     map(
         delimited(
             terminated(tag_no_case("unreleased"), space1),
-            opt(terminated(
-                take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                space1,
-            )),
+            opt(terminated(parse_browser_name, space1)),
             parse_version_keyword,
         ),
         QueryAtom::Unreleased,
@@ -213,7 +219,7 @@ fn parse_cover(input: &str) -> PResult<QueryAtom> {
 
 fn parse_supports(input: &str) -> PResult<QueryAtom> {
     map(
-        separated_pair(
+        tuple((
             opt(terminated(
                 alt((
                     value(SupportKind::Fully, tag_no_case("fully")),
@@ -222,9 +228,21 @@ fn parse_supports(input: &str) -> PResult<QueryAtom> {
                 space1,
             )),
             terminated(tag_no_case("supports"), space1),
-            take_while1(|c: char| c.is_alphanumeric() || c == '-'),
-        ),
-        |(kind, name)| QueryAtom::Supports(name, kind),
+            alt((
+                recognize(preceded(
+                    tag("mdn:"),
+                    take_while1(|c: char| c.is_alphanumeric() || c == '-' || c == '_' || c == '.'),
+                )),
+                take_while1(|c: char| c.is_alphanumeric() || c == '-'),
+            )),
+            opt(tuple((
+                space1,
+                tag_no_case("without"),
+                space1,
+                tag_no_case("prefix"),
+            ))),
+        )),
+        |(kind, _, name, without_prefix)| QueryAtom::Supports(name, kind, without_prefix.is_some()),
     )(input)
 }
 
@@ -277,27 +295,53 @@ fn parse_node(input: &str) -> PResult<QueryAtom> {
     )(input)
 }
 
+fn parse_browser_word(input: &str) -> PResult<'_, &str> {
+    take_while1(|c: char| c.is_ascii_alphabetic() || c == '_')(input)
+}
+
+/// Parse a browser name, which can also be a display name of multiple words
+/// like `Samsung Internet`.
+///
+/// Following words are only taken when they make up a longer known name,
+/// so versions and keywords after the name like `all` or `versions` are left as is.
+fn parse_browser_name(input: &str) -> PResult<'_, &str> {
+    let (mut rest, mut name) = parse_browser_word(input)?;
+    let mut remaining = rest;
+    while let Ok((next, _)) = preceded(space1, parse_browser_word)(remaining) {
+        let candidate = &input[..input.len() - next.len()];
+        if crate::data::browser_info(candidate).is_ok() {
+            name = candidate;
+            rest = next;
+        }
+        remaining = next;
+    }
+    Ok((rest, name))
+}
+
 fn parse_browser(input: &str) -> PResult<QueryAtom> {
+    let parse_version = || {
+        alt((
+            parse_version_range,
+            map(preceded(space1, tag_no_case("tp")), VersionRange::Accurate),
+        ))
+    };
     map(
-        pair(
-            take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-            alt((
-                parse_version_range,
-                map(preceded(space1, tag_no_case("tp")), VersionRange::Accurate),
-            )),
-        ),
+        pair(parse_browser_name, parse_version()),
         |(name, version)| QueryAtom::Browser(name, version),
     )(input)
 }
 
 fn parse_firefox_esr(input: &str) -> PResult<QueryAtom> {
-    value(
+    map(
+        preceded(
+            tuple((
+                alt((tag_no_case("firefox"), tag_no_case("fx"), tag_no_case("ff"))),
+                space1,
+                tag_no_case("esr"),
+            )),
+            opt(parse_version_range),
+        ),
         QueryAtom::FirefoxESR,
-        tuple((
-            alt((tag_no_case("firefox"), tag_no_case("fx"), tag_no_case("ff"))),
-            space1,
-            tag_no_case("esr"),
-        )),
     )(input)
 }
 
@@ -305,7 +349,9 @@ fn parse_opera_mini(input: &str) -> PResult<QueryAtom> {
     value(
         QueryAtom::OperaMini,
         tuple((
-            alt((tag_no_case("operamini"), tag_no_case("op_mini"))),
+            verify(parse_browser_name, |name: &str| {
+                crate::data::browser_info(name).is_ok_and(|info| info.name() == "op_mini")
+            }),
             space1,
             tag_no_case("all"),
         )),
@@ -332,6 +378,48 @@ fn parse_maintained_node(input: &str) -> PResult<QueryAtom> {
     )(input)
 }
 
+fn parse_node_lts(input: &str) -> PResult<'_, QueryAtom<'_>> {
+    value(
+        QueryAtom::NodeLts,
+        tuple((tag_no_case("node"), space1, tag_no_case("lts"))),
+    )(input)
+}
+
+fn parse_last_node_lts(input: &str) -> PResult<'_, QueryAtom<'_>> {
+    map(
+        delimited(
+            terminated(tag_no_case("last"), space1),
+            u16,
+            tuple((
+                space1,
+                tag_no_case("node"),
+                space1,
+                tag_no_case("lts"),
+                space1,
+                parse_version_keyword,
+            )),
+        ),
+        QueryAtom::LastNodeLts,
+    )(input)
+}
+
+fn parse_node_engines(input: &str) -> PResult<'_, QueryAtom<'_>> {
+    value(
+        QueryAtom::NodeEngines,
+        tuple((tag_no_case("node"), space1, tag_no_case("engines"))),
+    )(input)
+}
+
+fn parse_node_codename(input: &str) -> PResult<'_, QueryAtom<'_>> {
+    map(
+        preceded(
+            terminated(tag_no_case("node"), space1),
+            take_while1(|c: char| c.is_ascii_alphabetic()),
+        ),
+        QueryAtom::NodeCodename,
+    )(input)
+}
+
 fn parse_phantom(input: &str) -> PResult<QueryAtom> {
     map(
         preceded(
@@ -357,6 +445,47 @@ fn parse_dead(input: &str) -> PResult<QueryAtom> {
     value(QueryAtom::Dead, tag_no_case("dead"))(input)
 }
 
+fn parse_platform(input: &str) -> PResult<'_, QueryAtom<'_>> {
+    map(
+        alt((
+            value(BrowserType::Mobile, tag_no_case("mobile")),
+            value(BrowserType::Desktop, tag_no_case("desktop")),
+        )),
+        QueryAtom::Platform,
+    )(input)
+}
+
+fn parse_engine(input: &str) -> PResult<'_, QueryAtom<'_>> {
+    map(
+        alt((
+            value(Engine::Blink, tag_no_case("blink")),
+            value(Engine::EdgeHtml, tag_no_case("edgehtml")),
+            value(Engine::Gecko, tag_no_case("gecko")),
+            value(Engine::Presto, tag_no_case("presto")),
+            value(Engine::Trident, tag_no_case("trident")),
+            value(Engine::WebKit, tag_no_case("webkit")),
+        )),
+        QueryAtom::Engine,
+    )(input)
+}
+
+fn parse_engine_version(input: &str) -> PResult<'_, QueryAtom<'_>> {
+    map(
+        pair(
+            alt((
+                value(Engine::Blink, tag_no_case("chromium")),
+                value(Engine::Blink, tag_no_case("blink")),
+                value(Engine::EdgeHtml, tag_no_case("edgehtml")),
+                value(Engine::Gecko, tag_no_case("gecko")),
+                value(Engine::Trident, tag_no_case("trident")),
+                value(Engine::WebKit, tag_no_case("webkit")),
+            )),
+            parse_version_range,
+        ),
+        |(engine, version)| QueryAtom::EngineVersion(engine, version),
+    )(input)
+}
+
 fn parse_extends(input: &str) -> PResult<QueryAtom> {
     map(
         preceded(
@@ -389,13 +518,20 @@ fn parse_query_atom(input: &str) -> PResult<QueryAtom> {
         parse_node,
         parse_firefox_esr,
         parse_opera_mini,
-        parse_current_node,
-        parse_maintained_node,
+        alt((
+            parse_current_node,
+            parse_maintained_node,
+            parse_last_node_lts,
+            parse_node_lts,
+            parse_node_engines,
+            parse_node_codename,
+        )),
         parse_phantom,
-        parse_browser,
-        parse_browserslist_config,
+        alt((parse_engine_version, parse_platform, parse_engine)),
         parse_defaults,
         parse_dead,
+        parse_browser,
+        parse_browserslist_config,
         parse_extends,
         parse_unknown,
     ))(input)
@@ -473,12 +609,19 @@ pub(crate) fn parse_browserslist_query(input: &str) -> PResult<Vec<SingleQuery>>
     )(input)
 }
 
-pub(crate) fn parse_electron_version(version: &str) -> Result<f32, crate::error::Error> {
-    all_consuming(terminated(float, opt(pair(char('.'), u16))))(version)
+/// Parse Electron version as minor version like `28.2`,
+/// along with full version if patch version is given, like `28.2.3`.
+pub(crate) fn parse_electron_version(
+    version: &str,
+) -> Result<(f32, Option<Version>), crate::error::Error> {
+    let error = || crate::error::Error::UnknownElectronVersion(version.to_string());
+    let (minor, patch) = all_consuming(pair(float, opt(preceded(char('.'), u16))))(version)
         .map(|(_, v)| v)
-        .map_err(|_: nom::Err<nom::error::Error<_>>| {
-            crate::error::Error::UnknownElectronVersion(version.to_string())
-        })
+        .map_err(|_: nom::Err<nom::error::Error<_>>| error())?;
+    match patch {
+        Some(_) => Ok((minor, Some(version.parse().map_err(|_| error())?))),
+        None => Ok((minor, None)),
+    }
 }
 
 #[cfg(test)]
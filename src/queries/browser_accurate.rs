@@ -1,48 +1,16 @@
 use super::{Distrib, QueryResult};
-use crate::{error::Error, opts::Opts};
-use browserslist_data::caniuse::{get_browser_stat, normalize_version};
-use std::borrow::Cow;
+use crate::{data::normalize_browser_version, error::Error, opts::Opts};
 
 pub(super) fn browser_accurate(name: &str, version: &str, opts: &Opts) -> QueryResult {
-    let original_name = name;
-    let original_version = version;
-    let version = if original_version.eq_ignore_ascii_case("tp") {
-        "TP"
+    if let Some((name, version)) = normalize_browser_version(name, version, opts)? {
+        Ok(vec![Distrib::new(name, version)])
+    } else if opts.ignore_unknown_versions {
+        Ok(vec![])
     } else {
-        version
-    };
-
-    let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
-        .ok_or_else(|| Error::BrowserNotFound(name.to_string()))?;
-
-    if let Some(version) = normalize_version(
-        name,
-        stat,
-        if original_version.eq_ignore_ascii_case("tp") {
-            "TP"
-        } else {
-            version
-        },
-    ) {
-        Ok(vec![Distrib::new(name, version.to_owned())])
-    } else {
-        let version = if version.contains('.') {
-            Cow::Borrowed(version.trim_end_matches(".0"))
-        } else {
-            let mut v = version.to_owned();
-            v.push_str(".0");
-            Cow::Owned(v)
-        };
-        if let Some(version) = normalize_version(name, stat, &version) {
-            Ok(vec![Distrib::new(name, version.to_owned())])
-        } else if opts.ignore_unknown_versions {
-            Ok(vec![])
-        } else {
-            Err(Error::UnknownBrowserVersion(
-                original_name.to_string(),
-                original_version.to_string(),
-            ))
-        }
+        Err(Error::UnknownBrowserVersion(
+            name.to_string(),
+            version.to_string(),
+        ))
     }
 }
 
@@ -82,6 +50,38 @@ mod tests {
         );
     }
 
+    #[test_case("Samsung Internet 23", "samsung 23"; "display name")]
+    #[test_case("chrome for android 131", "and_chr 131"; "display name case insensitive")]
+    fn display_name(query: &str, expected: &str) {
+        assert_eq!(
+            crate::resolve([query], &Opts::default())
+                .unwrap()
+                .iter()
+                .map(|distrib| distrib.to_string())
+                .collect::<Vec<_>>(),
+            vec![expected]
+        );
+    }
+
+    #[test_case("defaults and firefox > 90", "firefox "; "defaults")]
+    #[test_case("dead and ie 11", "ie 11"; "dead")]
+    #[test_case("Samsung Internet 23 and not dead", "samsung 23"; "display name")]
+    fn keywords_around_name(query: &str, expected: &str) {
+        let distribs = crate::resolve([query], &Opts::default()).unwrap();
+        assert!(!distribs.is_empty());
+        assert!(distribs
+            .iter()
+            .all(|distrib| distrib.to_string().starts_with(expected)));
+    }
+
+    #[test]
+    fn version_alias_not_in_version_list() {
+        assert_eq!(
+            crate::resolve(["op_mob 59"], &Opts::default()).unwrap()[0].to_string(),
+            "op_mob 58"
+        );
+    }
+
     #[test]
     fn ignore_unknown_versions() {
         run_compare(
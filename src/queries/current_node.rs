@@ -1,7 +1,8 @@
 use super::{Distrib, QueryResult};
-use crate::error::Error;
+use crate::{error::Error, opts::Opts};
 
-pub(super) fn current_node() -> QueryResult {
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+pub(super) fn current_node(opts: &Opts) -> QueryResult {
     #[cfg(target_arch = "wasm32")]
     {
         #[cfg(feature = "wasm_bindgen")]
@@ -25,8 +26,21 @@ pub(super) fn current_node() -> QueryResult {
 
     #[cfg(not(target_arch = "wasm32"))]
     {
+        use crate::config;
+        use chrono::Local;
         use std::process::Command;
 
+        if opts.current_node_from_project {
+            let today = Local::now().naive_local().date();
+            let version = config::find_node_version(opts)?;
+            if let Some(version) = version
+                .as_deref()
+                .and_then(|version| resolve_node_version(version, today))
+            {
+                return Ok(vec![Distrib::new("node", version)]);
+            }
+        }
+
         let output = Command::new("node")
             .arg("-v")
             .output()
@@ -40,9 +54,40 @@ pub(super) fn current_node() -> QueryResult {
     }
 }
 
+/// Pick the newest known Node.js version which satisfies the pinned version,
+/// such as `18`, `v20.10.0`, `lts/hydrogen` or `>=18.17 <23`.
+/// `lts/*` means the newest release which is already in LTS at the given date.
+#[cfg(not(target_arch = "wasm32"))]
+fn resolve_node_version(pinned: &str, today: chrono::NaiveDate) -> Option<&'static str> {
+    use crate::{data, semver::VersionReq};
+    use browserslist_data::node;
+
+    match pinned.to_ascii_lowercase().as_str() {
+        "node" | "stable" | "latest" | "current" => node::versions().last().copied(),
+        "lts/*" => data::node_releases()
+            .rev()
+            .filter(|release| release.is_lts_at(today))
+            .find_map(|release| release.latest_version()),
+        pinned => match pinned.strip_prefix("lts/") {
+            Some(codename) => data::node_release(codename)?.latest_version(),
+            None => {
+                let req = VersionReq::parse(pinned)?;
+                node::versions()
+                    .iter()
+                    .rev()
+                    .find(|version| version.parse().is_ok_and(|version| req.matches(&version)))
+                    .copied()
+            }
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{opts::Opts, test::run_compare};
+    use super::*;
+    use crate::{data, opts::Opts, resolve, test::run_compare};
+    use chrono::NaiveDate;
+    use std::fs;
     use test_case::test_case;
 
     #[test_case("current node"; "basic")]
@@ -51,4 +96,53 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::default(), None);
     }
+
+    #[test_case(".nvmrc", "18\n", "node 18"; "nvmrc")]
+    #[test_case(".nvmrc", "lts/hydrogen", "node 18"; "nvmrc lts codename")]
+    #[test_case(".node-version", "v20.10.0", "node 20.10.0"; "node version")]
+    #[test_case(".tool-versions", "python 3.12.0\nnodejs 20.10.0\n", "node 20.10.0"; "tool versions")]
+    #[test_case(
+        "package.json", r#"{"engines":{"node":">=18.17 <21"}}"#, "node 20";
+        "package json engines"
+    )]
+    fn from_project(file_name: &str, content: &str, expected: &str) {
+        // each case needs its own directory since tests run in parallel
+        let case = content
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        let dir = std::env::temp_dir()
+            .join("browserslist-test-current-node")
+            .join(format!("{file_name}-{case}"));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join(file_name), content).unwrap();
+
+        let opts = Opts {
+            path: Some(dir.join("nested").to_string_lossy().into_owned()),
+            current_node_from_project: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve(["current node"], &opts).unwrap(),
+            resolve([expected], &Opts::default()).unwrap()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn lts_star_skips_future_lts() {
+        // Node.js 22 was released at 2024-04-24 but entered LTS at 2024-10-29
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        assert_eq!(
+            resolve_node_version("lts/*", today),
+            data::node_release("20").unwrap().latest_version()
+        );
+
+        let today = NaiveDate::from_ymd_opt(2024, 11, 1).unwrap();
+        assert_eq!(
+            resolve_node_version("lts/*", today),
+            data::node_release("22").unwrap().latest_version()
+        );
+    }
 }
@@ -1,15 +1,26 @@
 use super::{Distrib, QueryResult};
-use crate::{error::Error, parser::parse_electron_version};
+use crate::{error::Error, opts::Opts, parser::parse_electron_version, semver::Version};
 use browserslist_data::electron;
 
-pub(super) fn electron_accurate(version: &str) -> QueryResult {
+pub(super) fn electron_accurate(version: &str, opts: &Opts) -> QueryResult {
     let version_str = version;
-    let version: f32 = parse_electron_version(version)?;
+    let (version, full_version) = parse_electron_version(version)?;
 
-    let distribs = electron::get(version)
-        .map(|chromium_version| vec![Distrib::new("chrome", chromium_version)])
-        .ok_or_else(|| Error::UnknownElectronVersion(version_str.to_string()))?;
-    Ok(distribs)
+    let distrib = match full_version {
+        Some(full_version) => electron::full_versions()
+            .find(|(electron_version, _)| {
+                electron_version
+                    .parse::<Version>()
+                    .is_ok_and(|electron_version| electron_version == full_version)
+            })
+            .map(|(electron_version, chromium_version)| {
+                Distrib::electron_full(electron_version, chromium_version, opts)
+            }),
+        None => electron::get(version)
+            .map(|chromium_version| Distrib::electron(version, chromium_version, opts)),
+    };
+    let distrib = distrib.ok_or_else(|| Error::UnknownElectronVersion(version_str.to_string()))?;
+    Ok(vec![distrib])
 }
 
 #[cfg(test)]
@@ -48,7 +59,68 @@ mod tests {
         "electron 999.0", Error::UnknownElectronVersion(String::from("999.0"));
         "malformed version 4"
     )]
+    #[test_case(
+        "electron 28.2.99", Error::UnknownElectronVersion(String::from("28.2.99"));
+        "unknown patch version"
+    )]
+    #[test_case(
+        "electron >= 28.2.99", Error::UnknownElectronVersion(String::from("28.2.99"));
+        "unknown patch version in range"
+    )]
     fn invalid(query: &str, error: Error) {
         assert_eq!(should_failed(query, &Opts::default()), error);
     }
+
+    #[test_case("electron 1.1", "electron 1.1"; "basic")]
+    #[test_case("electron 0.20", "electron 0.20"; "keep original version string")]
+    #[test_case("electron >= 25", "electron 28.3, electron 28.2, electron 25.0"; "unbounded range")]
+    #[test_case("electron 0.36-1.1", "electron 1.1, electron 1.0, electron 0.36"; "bounded range")]
+    #[test_case("last 1 electron version", "electron 28.3"; "last versions")]
+    #[test_case("electron 28.2.3", "electron 28.2.3"; "patch version")]
+    fn keep_electron(query: &str, expected: &str) {
+        assert_eq!(
+            crate::resolve(
+                [query],
+                &Opts {
+                    keep_electron: true,
+                    ..Default::default()
+                }
+            )
+            .unwrap()
+            .iter()
+            .map(|distrib| distrib.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+            expected
+        );
+    }
+
+    #[test]
+    fn keep_electron_targets() {
+        let opts = Opts {
+            keep_electron: true,
+            ..Default::default()
+        };
+        let distribs = crate::resolve(["electron 25.0"], &opts).unwrap();
+        assert_eq!(crate::targets::babel(&distribs)["electron"], "25.0");
+        assert_eq!(crate::targets::swc(&distribs)["electron"], "25");
+        assert_eq!(crate::targets::esbuild(&distribs), ["chrome114"]);
+        assert_eq!(
+            crate::targets::lightningcss(&distribs).chrome,
+            Some(114 << 16)
+        );
+        assert_eq!(
+            crate::features::check_feature("arrow-functions", &distribs, &opts).unwrap()[0].1,
+            crate::features::SupportStatus::Full
+        );
+    }
+
+    #[test_case("electron 4.0.4", "chrome 69"; "patch version")]
+    #[test_case("electron 28.2.3", "chrome 120"; "patch version of newer release")]
+    fn patch_version(query: &str, expected: &str) {
+        assert_eq!(
+            crate::resolve([query], &Opts::default()).unwrap()[0].to_string(),
+            expected
+        );
+    }
 }
@@ -1,14 +1,32 @@
 use super::{Distrib, QueryResult};
-use crate::{error::Error, parser::parse_electron_version};
+use crate::{error::Error, opts::Opts, parser::parse_electron_version, semver::Version};
 use browserslist_data::electron;
+use std::cmp::Ordering;
 
-pub(super) fn electron_bounded_range(from: &str, to: &str) -> QueryResult {
+pub(super) fn electron_bounded_range(from: &str, to: &str, opts: &Opts) -> QueryResult {
     let from_str = from;
     let to_str = to;
-    let from: f32 = parse_electron_version(from)?;
-    let to: f32 = parse_electron_version(to)?;
+    let (from, from_full) = parse_electron_version(from)?;
+    let (to, to_full) = parse_electron_version(to)?;
 
-    let versions = electron::bounded_range(from..to).map_err(|v| {
+    if from_full.is_some() || to_full.is_some() {
+        let from = full_bound(from_str, from, from_full)?;
+        let to = full_bound(to_str, to, to_full)?;
+        let distribs = electron::full_versions()
+            .filter(|(electron_version, _)| {
+                electron_version.parse::<Version>().is_ok_and(|version| {
+                    from.compare(version) != Ordering::Greater
+                        && to.compare(version) != Ordering::Less
+                })
+            })
+            .map(|(electron_version, chromium_version)| {
+                Distrib::electron_full(electron_version, chromium_version, opts)
+            })
+            .collect();
+        return Ok(distribs);
+    }
+
+    electron::bounded_range(from..to).map_err(|v| {
         let v = match v {
             v if v == from => from_str,
             v if v == to => to_str,
@@ -18,13 +36,54 @@ pub(super) fn electron_bounded_range(from: &str, to: &str) -> QueryResult {
         Error::UnknownElectronVersion(v.into())
     })?;
 
-    let distribs = versions
-        .iter()
-        .map(|version| Distrib::new("chrome", *version))
+    let distribs = electron::versions()
+        .filter(|(electron_version, _)| (from..=to).contains(electron_version))
+        .map(|(electron_version, chromium_version)| {
+            Distrib::electron(electron_version, chromium_version, opts)
+        })
         .collect();
     Ok(distribs)
 }
 
+/// Bound of patch-level version range.
+/// Bound without patch version, like `28.2`, covers all of its patch versions.
+struct FullBound {
+    version: Version,
+    with_patch: bool,
+}
+
+impl FullBound {
+    fn compare(&self, version: Version) -> Ordering {
+        if self.with_patch {
+            self.version.cmp(&version)
+        } else {
+            (self.version.major(), self.version.minor()).cmp(&(version.major(), version.minor()))
+        }
+    }
+}
+
+fn full_bound(
+    version_str: &str,
+    version: f32,
+    full_version: Option<Version>,
+) -> Result<FullBound, Error> {
+    let known = match full_version {
+        Some(full_version) => electron::full_versions().any(|(electron_version, _)| {
+            electron_version
+                .parse::<Version>()
+                .is_ok_and(|electron_version| electron_version == full_version)
+        }),
+        None => electron::get(version).is_some(),
+    };
+    match version_str.parse() {
+        Ok(version) if known => Ok(FullBound {
+            version,
+            with_patch: full_version.is_some(),
+        }),
+        _ => Err(Error::UnknownElectronVersion(version_str.into())),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,7 +95,6 @@ mod tests {
 
     #[test_case("electron 0.36-1.2"; "basic")]
     #[test_case("Electron 0.37-1.0"; "case insensitive")]
-    #[test_case("electron 0.37.5-1.0.3"; "with semver patch version")]
     fn valid(query: &str) {
         run_compare(query, &Opts::default(), None);
     }
@@ -57,7 +115,33 @@ mod tests {
         "electron 1.1.1.1-2", Error::UnknownElectronVersion(String::from("1.1.1.1"));
         "malformed version 2"
     )]
+    #[test_case(
+        "electron 28.2.99-28.3", Error::UnknownElectronVersion(String::from("28.2.99"));
+        "unknown patch version"
+    )]
     fn invalid(query: &str, error: Error) {
         assert_eq!(should_failed(query, &Opts::default()), error);
     }
+
+    #[test_case("electron 28.2.3-28.3", &["electron 28.2.3", "electron 28.3.0"], &["electron 28.2.0", "electron 28.2.2"]; "patch lower bound")]
+    #[test_case("electron 4.0-28.2.3", &["electron 4.0.4", "electron 28.2.3"], &["electron 28.3.0"]; "patch upper bound")]
+    fn patch_version(query: &str, included: &[&str], excluded: &[&str]) {
+        let distribs = crate::resolve(
+            [query],
+            &Opts {
+                keep_electron: true,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .iter()
+        .map(|distrib| distrib.to_string())
+        .collect::<Vec<_>>();
+        assert!(included
+            .iter()
+            .all(|version| distribs.iter().any(|d| d == version)));
+        assert!(!excluded
+            .iter()
+            .any(|version| distribs.iter().any(|d| d == version)));
+    }
 }
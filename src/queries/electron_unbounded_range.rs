@@ -1,9 +1,47 @@
 use super::{Distrib, QueryResult};
-use crate::{parser::parse_electron_version, parser::Comparator};
+use crate::{
+    error::Error,
+    opts::Opts,
+    parser::{parse_electron_version, Comparator},
+    semver::Version,
+};
 use browserslist_data::electron;
 
-pub(super) fn electron_unbounded_range(comparator: Comparator, version: &str) -> QueryResult {
-    let version: f32 = parse_electron_version(version)?;
+pub(super) fn electron_unbounded_range(
+    comparator: Comparator,
+    version: &str,
+    opts: &Opts,
+) -> QueryResult {
+    let version_str = version;
+    let (version, full_version) = parse_electron_version(version)?;
+
+    if let Some(full_version) = full_version {
+        let known = electron::full_versions().any(|(electron_version, _)| {
+            electron_version
+                .parse::<Version>()
+                .is_ok_and(|electron_version| electron_version == full_version)
+        });
+        if !known {
+            return Err(Error::UnknownElectronVersion(version_str.into()));
+        }
+
+        let distribs = electron::full_versions()
+            .filter(|(electron_version, _)| {
+                electron_version
+                    .parse::<Version>()
+                    .is_ok_and(|electron_version| match comparator {
+                        Comparator::Greater => electron_version > full_version,
+                        Comparator::Less => electron_version < full_version,
+                        Comparator::GreaterOrEqual => electron_version >= full_version,
+                        Comparator::LessOrEqual => electron_version <= full_version,
+                    })
+            })
+            .map(|(electron_version, chromium_version)| {
+                Distrib::electron_full(electron_version, chromium_version, opts)
+            })
+            .collect();
+        return Ok(distribs);
+    }
 
     let distribs = electron::versions()
         .filter(|(electron_version, _)| match comparator {
@@ -12,7 +50,9 @@ pub(super) fn electron_unbounded_range(comparator: Comparator, version: &str) ->
             Comparator::GreaterOrEqual => *electron_version >= version,
             Comparator::LessOrEqual => *electron_version <= version,
         })
-        .map(|(_, chromium_version)| Distrib::new("chrome", chromium_version))
+        .map(|(electron_version, chromium_version)| {
+            Distrib::electron(electron_version, chromium_version, opts)
+        })
         .collect();
     Ok(distribs)
 }
@@ -28,7 +68,6 @@ mod tests {
 
     #[test_case("electron <= 0.21"; "basic")]
     #[test_case("Electron < 0.21"; "case insensitive")]
-    #[test_case("Electron < 0.21.5"; "with semver patch version")]
     fn valid(query: &str) {
         run_compare(query, &Opts::default(), None);
     }
@@ -41,7 +80,43 @@ mod tests {
         "electron >= 1.1.1.1", Error::UnknownElectronVersion(String::from("1.1.1.1"));
         "malformed version 2"
     )]
+    #[test_case(
+        "electron >= 28.2.99", Error::UnknownElectronVersion(String::from("28.2.99"));
+        "unknown patch version"
+    )]
     fn invalid(query: &str, error: Error) {
         assert_eq!(should_failed(query, &Opts::default()), error);
     }
+
+    #[test_case("electron >= 28.2.3", &["electron 28.2.3", "electron 28.3.0"], &["electron 28.2.0", "electron 28.2.2"]; "greater or equal")]
+    #[test_case("electron > 28.2.3", &["electron 28.3.0"], &["electron 28.2.3"]; "greater")]
+    #[test_case("electron < 4.0.4", &["electron 1.0.0"], &["electron 4.0.4", "electron 28.2.3"]; "less")]
+    fn patch_version(query: &str, included: &[&str], excluded: &[&str]) {
+        let distribs = crate::resolve(
+            [query],
+            &Opts {
+                keep_electron: true,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .iter()
+        .map(|distrib| distrib.to_string())
+        .collect::<Vec<_>>();
+        assert!(included
+            .iter()
+            .all(|version| distribs.iter().any(|d| d == version)));
+        assert!(!excluded
+            .iter()
+            .any(|version| distribs.iter().any(|d| d == version)));
+    }
+
+    #[test]
+    fn patch_version_to_chrome() {
+        let distribs = crate::resolve(["electron >= 28.2.3"], &Opts::default()).unwrap();
+        assert!(distribs
+            .iter()
+            .any(|distrib| distrib.to_string() == "chrome 120"));
+        assert!(distribs.iter().all(|distrib| distrib.name() == "chrome"));
+    }
 }
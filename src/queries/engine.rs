@@ -0,0 +1,45 @@
+use super::{Distrib, QueryResult};
+use crate::{data::Engine, opts::Opts};
+use browserslist_data::caniuse::iter_browser_stat;
+
+pub(super) fn engine(engine: Engine, opts: &Opts) -> QueryResult {
+    let distribs = iter_browser_stat(opts.mobile_to_desktop)
+        .flat_map(|(name, version_list)| {
+            version_list
+                .iter()
+                .filter(move |version| version.released && version.engine == Some(engine))
+                .map(move |version| Distrib::new(name, version.version()))
+        })
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{data::Engine, opts::Opts, resolve};
+    use test_case::test_case;
+
+    #[test_case("last 2 versions and blink", Engine::Blink; "blink")]
+    #[test_case("ie >= 9, firefox > 90 and Gecko", Engine::Gecko; "gecko")]
+    #[test_case("ie 11 or webkit", Engine::WebKit; "webkit")]
+    #[test_case("opera 12.1 and presto", Engine::Presto; "presto")]
+    fn valid(query: &str, engine: Engine) {
+        let distribs = resolve([query], &Opts::default()).unwrap();
+        assert!(distribs
+            .iter()
+            .any(|distrib| distrib.engine() == Some(engine)));
+    }
+
+    #[test]
+    fn not_engine() {
+        let distribs = resolve(
+            ["last 2 versions", "not gecko", "not trident"],
+            &Opts::default(),
+        )
+        .unwrap();
+        assert!(!distribs.is_empty());
+        assert!(distribs
+            .iter()
+            .all(|distrib| !matches!(distrib.engine(), Some(Engine::Gecko | Engine::Trident))));
+    }
+}
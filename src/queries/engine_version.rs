@@ -0,0 +1,82 @@
+use super::{Distrib, QueryResult};
+use crate::{
+    data::Engine,
+    opts::Opts,
+    parser::{Comparator, VersionRange},
+    semver::loose_compare,
+};
+use browserslist_data::caniuse::iter_browser_stat;
+use std::cmp::Ordering;
+
+pub(super) fn engine_version(engine: Engine, range: VersionRange, opts: &Opts) -> QueryResult {
+    let matches = |version: &str| match range {
+        VersionRange::Bounded(from, to) => {
+            loose_compare(version, from).is_ge() && loose_compare(version, to).is_le()
+        }
+        VersionRange::Unbounded(ref comparator, target) => {
+            let ord = loose_compare(version, target);
+            match comparator {
+                Comparator::Greater => ord == Ordering::Greater,
+                Comparator::Less => ord == Ordering::Less,
+                Comparator::GreaterOrEqual => ord != Ordering::Less,
+                Comparator::LessOrEqual => ord != Ordering::Greater,
+            }
+        }
+        VersionRange::Accurate(target) => loose_compare(version, target).is_eq(),
+    };
+
+    let distribs = iter_browser_stat(opts.mobile_to_desktop)
+        .flat_map(|(name, version_list)| {
+            version_list
+                .iter()
+                .filter(|version| {
+                    version.released
+                        && version.engine == Some(engine)
+                        && version.engine_version().is_some_and(matches)
+                })
+                .map(move |version| Distrib::new(name, version.version()))
+        })
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{opts::Opts, resolve};
+    use test_case::test_case;
+
+    #[test_case("chromium >= 115", &["samsung 23", "chrome 120", "opera 105"], &["samsung 22", "chrome 100", "firefox 128"]; "chromium")]
+    #[test_case("chromium >= 110", &["and_qq 14.9", "op_mob 80"], &["and_uc 15.5", "op_mob 12.1"]; "mobile browsers")]
+    #[test_case("chromium 78", &["and_uc 15.5"], &["and_qq 14.9"]; "uc browser")]
+    #[test_case("Blink 110-114", &["chrome 110", "opera 100"], &["chrome 115", "opera 105"]; "bounded")]
+    #[test_case("webkit >= 616", &["safari 17.2", "ios_saf 17.2"], &["safari 15.4", "chrome 120"]; "webkit")]
+    #[test_case("gecko 93", &["firefox 93"], &["firefox 92"]; "accurate")]
+    fn valid(query: &str, included: &[&str], excluded: &[&str]) {
+        let distribs = resolve([query], &Opts::default())
+            .unwrap()
+            .into_iter()
+            .map(|distrib| distrib.to_string())
+            .collect::<Vec<_>>();
+        assert!(included
+            .iter()
+            .all(|d| distribs.iter().any(|distrib| distrib == d)));
+        assert!(excluded
+            .iter()
+            .all(|d| distribs.iter().all(|distrib| distrib != d)));
+    }
+
+    #[test_case("and_qq"; "qq browser")]
+    #[test_case("and_uc"; "uc browser")]
+    #[test_case("baidu"; "baidu browser")]
+    #[test_case("op_mob"; "opera mobile")]
+    fn chromium_based_mobile_browsers(name: &str) {
+        let versions = crate::data::browser_versions(name, &Opts::default())
+            .unwrap()
+            .filter(|version| version.engine() == Some(crate::data::Engine::Blink))
+            .collect::<Vec<_>>();
+        assert!(!versions.is_empty());
+        assert!(versions
+            .iter()
+            .all(|version| version.engine_version().is_some()));
+    }
+}
@@ -1,14 +1,67 @@
 use super::{Distrib, QueryResult};
+use crate::{
+    error::Error,
+    opts::Opts,
+    parser::{Comparator, VersionRange},
+    semver::compare,
+};
+use browserslist_data::firefox_esr;
+use chrono::Local;
+use std::cmp::Ordering;
 
-pub(super) fn firefox_esr() -> QueryResult {
-    Ok(vec![
-        Distrib::new("firefox", "140"),
-    ])
+pub(super) fn firefox_esr(range: Option<VersionRange>, opts: &Opts) -> QueryResult {
+    let now = Local::now().naive_local().date();
+
+    let Some(range) = range else {
+        let distribs = firefox_esr::supported(now)
+            .map(|release| Distrib::new("firefox", release.version))
+            .collect();
+        return Ok(distribs);
+    };
+
+    // versions which reached end of life aren't ESR anymore
+    let supported = firefox_esr::supported(now);
+    let distribs = match range {
+        VersionRange::Accurate(version) => {
+            let distribs = supported
+                .filter(|release| compare(release.version, version) == Ordering::Equal)
+                .map(|release| Distrib::new("firefox", release.version))
+                .collect::<Vec<_>>();
+            if distribs.is_empty() && !opts.ignore_unknown_versions {
+                return Err(Error::UnknownBrowserVersion(
+                    String::from("firefox esr"),
+                    version.to_string(),
+                ));
+            }
+            distribs
+        }
+        VersionRange::Unbounded(comparator, version) => supported
+            .filter(|release| {
+                let ordering = compare(release.version, version);
+                match comparator {
+                    Comparator::Greater => ordering == Ordering::Greater,
+                    Comparator::Less => ordering == Ordering::Less,
+                    Comparator::GreaterOrEqual => ordering != Ordering::Less,
+                    Comparator::LessOrEqual => ordering != Ordering::Greater,
+                }
+            })
+            .map(|release| Distrib::new("firefox", release.version))
+            .collect(),
+        VersionRange::Bounded(from, to) => supported
+            .filter(|release| {
+                compare(release.version, from) != Ordering::Less
+                    && compare(release.version, to) != Ordering::Greater
+            })
+            .map(|release| Distrib::new("firefox", release.version))
+            .collect(),
+    };
+    Ok(distribs)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{opts::Opts, test::run_compare};
+    use super::*;
+    use crate::{resolve, test::should_failed};
     use test_case::test_case;
 
     #[test_case("firefox esr"; "firefox")]
@@ -18,6 +71,75 @@ mod tests {
     #[test_case("fx esr"; "fx")]
     #[test_case("Fx ESR"; "fx case insensitive")]
     fn valid(query: &str) {
-        run_compare(query, &Opts::default(), None);
+        let distribs = resolve([query], &Opts::default()).unwrap();
+        assert!(!distribs.is_empty());
+        assert_eq!(
+            distribs,
+            resolve(["firefox esr"], &Opts::default()).unwrap()
+        );
+    }
+
+    #[test_case("2023-08-01", &["102", "115"], &["128"]; "overlapped")]
+    #[test_case("2023-09-26", &["115"], &["102"]; "end of life")]
+    #[test_case("2024-12-01", &["115"], &["102"]; "extended support")]
+    fn supported_at(date: &str, included: &[&str], excluded: &[&str]) {
+        let versions = firefox_esr::supported(date.parse().unwrap())
+            .map(|release| release.version)
+            .collect::<Vec<_>>();
+        assert!(included.iter().all(|version| versions.contains(version)));
+        assert!(excluded.iter().all(|version| !versions.contains(version)));
+    }
+
+    #[test_case("firefox esr >= 115", |version| version >= 115; "unbounded range")]
+    #[test_case("ff esr 102-128", |version| (102..=128).contains(&version); "bounded range")]
+    fn range(query: &str, filter: fn(u32) -> bool) {
+        let now = Local::now().naive_local().date();
+        let expected = firefox_esr::supported(now)
+            .filter(|release| filter(release.version.parse().unwrap()))
+            .map(|release| Distrib::new("firefox", release.version))
+            .collect::<Vec<_>>();
+        let mut distribs = resolve([query], &Opts::default()).unwrap();
+        distribs.reverse();
+        assert_eq!(distribs, expected);
+    }
+
+    #[test]
+    fn accurate() {
+        let now = Local::now().naive_local().date();
+        let latest = firefox_esr::supported(now).last().unwrap().version;
+        assert_eq!(
+            resolve([format!("fx esr {latest}")], &Opts::default()).unwrap(),
+            [Distrib::new("firefox", latest)]
+        );
+    }
+
+    #[test]
+    fn end_of_life() {
+        // Firefox 102 ESR reached end of life at 2023-09-26
+        assert!(resolve(["firefox esr >= 102"], &Opts::default())
+            .unwrap()
+            .iter()
+            .all(|distrib| distrib.version() != "102"));
+        assert_eq!(
+            should_failed("firefox esr 102", &Opts::default()),
+            Error::UnknownBrowserVersion(String::from("firefox esr"), String::from("102"))
+        );
+    }
+
+    #[test]
+    fn not_esr() {
+        assert_eq!(
+            should_failed("firefox esr 116", &Opts::default()),
+            Error::UnknownBrowserVersion(String::from("firefox esr"), String::from("116"))
+        );
+        assert!(resolve(
+            ["firefox esr 116"],
+            &Opts {
+                ignore_unknown_versions: true,
+                ..Default::default()
+            }
+        )
+        .unwrap()
+        .is_empty());
     }
 }
@@ -1,11 +1,14 @@
 use super::{Distrib, QueryResult};
+use crate::opts::Opts;
 use browserslist_data::electron;
 
-pub(super) fn last_n_electron(count: usize) -> QueryResult {
+pub(super) fn last_n_electron(count: usize, opts: &Opts) -> QueryResult {
     let distribs = electron::versions()
         .rev()
         .take(count)
-        .map(|(_, version)| Distrib::new("chrome", version))
+        .map(|(electron_version, chromium_version)| {
+            Distrib::electron(electron_version, chromium_version, opts)
+        })
         .collect();
     Ok(distribs)
 }
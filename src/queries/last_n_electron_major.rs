@@ -1,8 +1,9 @@
 use super::{Distrib, QueryResult};
+use crate::opts::Opts;
 use browserslist_data::electron;
 use itertools::Itertools;
 
-pub(super) fn last_n_electron_major(count: usize) -> QueryResult {
+pub(super) fn last_n_electron_major(count: usize, opts: &Opts) -> QueryResult {
     let minimum = electron::versions()
         .rev()
         .dedup()
@@ -13,7 +14,9 @@ pub(super) fn last_n_electron_major(count: usize) -> QueryResult {
     let distribs = electron::versions()
         .filter(|(electron_version, _)| *electron_version >= minimum)
         .rev()
-        .map(|(_, chromium_version)| Distrib::new("chrome", chromium_version))
+        .map(|(electron_version, chromium_version)| {
+            Distrib::electron(electron_version, chromium_version, opts)
+        })
         .collect();
 
     Ok(distribs)
@@ -0,0 +1,34 @@
+use super::{Distrib, QueryResult};
+use crate::data;
+use chrono::Local;
+
+pub(super) fn last_n_node_lts(count: usize) -> QueryResult {
+    let now = Local::now().naive_local().date();
+    let distribs = data::node_releases()
+        .rev()
+        .filter(|release| release.lts().is_some_and(|lts| lts <= now))
+        .filter_map(|release| release.latest_version())
+        .take(count)
+        .map(|version| Distrib::new("node", version))
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{opts::Opts, resolve};
+    use test_case::test_case;
+
+    #[test_case("last 2 node lts versions"; "basic")]
+    #[test_case("Last 2 Node LTS Versions"; "case insensitive")]
+    #[test_case("last 2 node lts version"; "support pluralization")]
+    fn valid(query: &str) {
+        let distribs = resolve([query], &Opts::default()).unwrap();
+        assert_eq!(distribs.len(), 2);
+        assert!(distribs.iter().all(|distrib| distrib.name() == "node"));
+        assert_ne!(
+            distribs[0].version().split('.').next(),
+            distribs[1].version().split('.').next()
+        );
+    }
+}
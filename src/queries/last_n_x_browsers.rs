@@ -36,4 +36,13 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::default(), None);
     }
+
+    #[test_case("last 2 Samsung Internet versions", "last 2 samsung versions"; "display name")]
+    #[test_case("last 1 chrome for android version", "last 1 and_chr version"; "display name case insensitive")]
+    fn display_name(query: &str, expected: &str) {
+        assert_eq!(
+            crate::resolve([query], &Opts::default()).unwrap(),
+            crate::resolve([expected], &Opts::default()).unwrap()
+        );
+    }
 }
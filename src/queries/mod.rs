@@ -1,12 +1,14 @@
 use crate::{
+    data::{self, BrowserType, BrowserVersion, Engine},
     error::Error,
     opts::Opts,
     parser::{QueryAtom, Stats, VersionRange},
-    semver::Version,
+    semver::{DistribVersion, Version},
 };
-use browserslist_data::caniuse;
+use browserslist_data::{caniuse, electron};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, fmt::Display};
+use std::{borrow::Cow, cmp::Ordering, fmt::Display};
 
 mod browser_accurate;
 mod browser_bounded_range;
@@ -20,6 +22,8 @@ mod defaults;
 mod electron_accurate;
 mod electron_bounded_range;
 mod electron_unbounded_range;
+mod engine;
+mod engine_version;
 mod extends;
 mod firefox_esr;
 mod last_n_browsers;
@@ -27,17 +31,22 @@ mod last_n_electron;
 mod last_n_electron_major;
 mod last_n_major_browsers;
 mod last_n_node;
+mod last_n_node_lts;
 mod last_n_node_major;
 mod last_n_x_browsers;
 mod last_n_x_major_browsers;
 mod maintained_node;
 mod node_accurate;
 mod node_bounded_range;
+mod node_codename;
+mod node_engines;
+mod node_lts;
 mod node_unbounded_range;
 mod op_mini;
 mod percentage;
 mod percentage_by_region;
 mod phantom;
+mod platform;
 mod since;
 mod supports;
 mod unreleased_browsers;
@@ -45,9 +54,12 @@ mod unreleased_electron;
 mod unreleased_x_browsers;
 mod years;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 /// Representation of browser name (or `node`) and its version.
 ///
+/// Distribs are ordered by name alphabetically, then by version from newest to oldest,
+/// which is the same order as the result of [`resolve`](crate::resolve).
+///
 /// When converting it to string, it will be formatted as the output of
 /// [browserslist](https://github.com/browserslist/browserslist). For example:
 ///
@@ -67,8 +79,34 @@ impl Distrib {
         Self(name, version.into())
     }
 
+    /// Create a distrib of an Electron version, which is converted to its Chromium version
+    /// unless the `keep_electron` option is enabled.
+    fn electron(electron_version: f32, chromium_version: &'static str, opts: &Opts) -> Self {
+        match electron::version_name(electron_version) {
+            Some(version) if opts.keep_electron => Self::new("electron", version),
+            _ => Self::new("chrome", chromium_version),
+        }
+    }
+
+    /// Same as [`Distrib::electron`], but for patch-level version like `28.2.3`,
+    /// which is converted to the major version of its own Chromium build.
+    fn electron_full(
+        electron_version: &'static str,
+        chromium_version: &'static str,
+        opts: &Opts,
+    ) -> Self {
+        if opts.keep_electron {
+            Self::new("electron", electron_version)
+        } else {
+            let major = chromium_version
+                .split_once('.')
+                .map_or(chromium_version, |(major, _)| major);
+            Self::new("chrome", major)
+        }
+    }
+
     #[inline]
-    /// Return browser name, or `node`.
+    /// Return browser name, `node`, or `electron` if the `keep_electron` option is enabled.
     ///
     /// ```
     /// use browserslist::{Opts, resolve};
@@ -94,6 +132,178 @@ impl Distrib {
     pub fn version(&self) -> &str {
         &self.1
     }
+
+    /// Return parsed version which is comparable.
+    ///
+    /// Unrecognized version will be treated as `0.0.0`.
+    ///
+    /// ```
+    /// use browserslist::{DistribVersion, Opts, resolve};
+    ///
+    /// let distribs = resolve(["android 4.4.3", "safari tp"], &Opts::default()).unwrap();
+    ///
+    /// assert!(matches!(
+    ///     distribs[0].parsed_version(),
+    ///     DistribVersion::Numeric(version) if version.major() == 4 && version.patch() == 3
+    /// ));
+    /// assert_eq!(distribs[1].parsed_version(), DistribVersion::TechnologyPreview);
+    /// ```
+    pub fn parsed_version(&self) -> DistribVersion {
+        self.1
+            .parse()
+            .unwrap_or(DistribVersion::Numeric(Version::default()))
+    }
+
+    /// Return release date, or `None` if it's unreleased or unknown.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["firefox 93"], &Opts::default()).unwrap()[0];
+    ///
+    /// assert!(distrib.release_date().is_some());
+    /// ```
+    pub fn release_date(&self) -> Option<NaiveDate> {
+        self.browser_version()
+            .or_else(|| data::find_browser_version(self.0, &self.1, true))
+            .and_then(|version| version.release_date())
+    }
+
+    /// Return global usage in percentage, or `None` if it's unknown.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["ie 6"], &Opts::default()).unwrap()[0];
+    ///
+    /// assert!(distrib.global_usage().is_some());
+    /// ```
+    pub fn global_usage(&self) -> Option<f32> {
+        self.browser_version().map(|version| version.global_usage())
+    }
+
+    /// Return whether this version has been released.
+    ///
+    /// Node.js and Electron versions are always released.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["firefox 93"], &Opts::default()).unwrap()[0];
+    ///
+    /// assert!(distrib.is_released());
+    /// ```
+    pub fn is_released(&self) -> bool {
+        if self.0 == "node" || self.0 == "electron" {
+            true
+        } else {
+            self.browser_version()
+                .or_else(|| data::find_browser_version(self.0, &self.1, true))
+                .is_some_and(|version| version.released())
+        }
+    }
+
+    /// Return display name of the browser, such as `Chrome for Android` for `and_chr`.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distribs = resolve(["and_chr > 0", "node 16.0"], &Opts::default()).unwrap();
+    ///
+    /// assert_eq!(distribs[0].display_name(), "Chrome for Android");
+    /// assert_eq!(distribs[1].display_name(), "Node.js");
+    /// ```
+    pub fn display_name(&self) -> &'static str {
+        if self.0 == "node" {
+            "Node.js"
+        } else if self.0 == "electron" {
+            "Electron"
+        } else {
+            data::browser_info(self.0).map_or(self.0, |info| info.display_name())
+        }
+    }
+
+    /// Return whether the browser is for desktop or mobile, or `None` for Node.js and Electron.
+    pub fn browser_type(&self) -> Option<BrowserType> {
+        data::browser_info(self.0)
+            .ok()
+            .map(|info| info.browser_type())
+    }
+
+    /// Return rendering engine, or `None` if it's unknown.
+    ///
+    /// Electron versions kept by the `keep_electron` option are treated as Blink.
+    ///
+    /// ```
+    /// use browserslist::{data::Engine, Opts, resolve};
+    ///
+    /// let distrib = &resolve(["firefox 93"], &Opts::default()).unwrap()[0];
+    ///
+    /// assert_eq!(distrib.engine(), Some(Engine::Gecko));
+    /// ```
+    pub fn engine(&self) -> Option<Engine> {
+        if self.electron_chromium_version().is_some() {
+            return Some(Engine::Blink);
+        }
+        self.browser_version()
+            .or_else(|| data::find_browser_version(self.0, &self.1, true))
+            .and_then(|version| version.engine())
+    }
+
+    /// Return version of the rendering engine, or `None` if it's unknown.
+    ///
+    /// ```
+    /// use browserslist::{data::Engine, Opts, resolve};
+    ///
+    /// let distrib = &resolve(["samsung 23"], &Opts::default()).unwrap()[0];
+    ///
+    /// assert_eq!(distrib.engine(), Some(Engine::Blink));
+    /// assert_eq!(distrib.engine_version().unwrap().major(), 115);
+    /// ```
+    pub fn engine_version(&self) -> Option<Version> {
+        if let Some(chromium_version) = self.electron_chromium_version() {
+            return chromium_version.parse().ok();
+        }
+        self.browser_version()
+            .or_else(|| data::find_browser_version(self.0, &self.1, true))
+            .and_then(|version| version.engine_version())
+            .and_then(|version| version.parse().ok())
+    }
+
+    /// Convert an Electron distrib into the Chrome distrib of its Chromium major version.
+    pub(crate) fn electron_to_chrome(&self) -> Option<Distrib> {
+        let chromium_version = self.electron_chromium_version()?;
+        let major = chromium_version
+            .split_once('.')
+            .map_or(chromium_version, |(major, _)| major);
+        Some(Self::new("chrome", major))
+    }
+
+    fn electron_chromium_version(&self) -> Option<&'static str> {
+        if self.0 != "electron" {
+            return None;
+        }
+        electron::get_full(&self.1).or_else(|| self.1.parse().ok().and_then(electron::get))
+    }
+
+    fn browser_version(&self) -> Option<BrowserVersion> {
+        data::find_browser_version(self.0, &self.1, false)
+    }
+}
+
+impl Ord for Distrib {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .cmp(other.0)
+            .then_with(|| other.parsed_version().cmp(&self.parsed_version()))
+            .then_with(|| self.1.cmp(&other.1))
+    }
+}
+
+impl PartialOrd for Distrib {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl Display for Distrib {
@@ -113,9 +323,9 @@ pub fn query(atom: QueryAtom, opts: &Opts) -> QueryResult {
         } if name.eq_ignore_ascii_case("electron") => {
             let count = count as usize;
             if major {
-                last_n_electron_major::last_n_electron_major(count)
+                last_n_electron_major::last_n_electron_major(count, opts)
             } else {
-                last_n_electron::last_n_electron(count)
+                last_n_electron::last_n_electron(count, opts)
             }
         }
         QueryAtom::Last {
@@ -181,15 +391,17 @@ pub fn query(atom: QueryAtom, opts: &Opts) -> QueryResult {
             coverage,
             stats: Stats::Region(region),
         } => cover_by_region::cover_by_region(coverage, region),
-        QueryAtom::Supports(name, kind) => supports::supports(name, kind, opts),
+        QueryAtom::Supports(name, kind, without_prefix) => {
+            supports::supports(name, kind, without_prefix, opts)
+        }
         QueryAtom::Electron(VersionRange::Bounded(from, to)) => {
-            electron_bounded_range::electron_bounded_range(from, to)
+            electron_bounded_range::electron_bounded_range(from, to, opts)
         }
         QueryAtom::Electron(VersionRange::Unbounded(comparator, version)) => {
-            electron_unbounded_range::electron_unbounded_range(comparator, version)
+            electron_unbounded_range::electron_unbounded_range(comparator, version, opts)
         }
         QueryAtom::Electron(VersionRange::Accurate(version)) => {
-            electron_accurate::electron_accurate(version)
+            electron_accurate::electron_accurate(version, opts)
         }
         QueryAtom::Node(VersionRange::Bounded(from, to)) => {
             node_bounded_range::node_bounded_range(from, to)
@@ -209,14 +421,23 @@ pub fn query(atom: QueryAtom, opts: &Opts) -> QueryResult {
         QueryAtom::Browser(name, VersionRange::Accurate(version)) => {
             browser_accurate::browser_accurate(name, version, opts)
         }
-        QueryAtom::FirefoxESR => firefox_esr::firefox_esr(),
+        QueryAtom::FirefoxESR(range) => firefox_esr::firefox_esr(range, opts),
         QueryAtom::OperaMini => op_mini::op_mini(),
-        QueryAtom::CurrentNode => current_node::current_node(),
+        QueryAtom::CurrentNode => current_node::current_node(opts),
         QueryAtom::MaintainedNode => maintained_node::maintained_node(),
+        QueryAtom::NodeLts => node_lts::node_lts(),
+        QueryAtom::LastNodeLts(count) => last_n_node_lts::last_n_node_lts(count as usize),
+        QueryAtom::NodeCodename(codename) => node_codename::node_codename(codename, opts),
+        QueryAtom::NodeEngines => node_engines::node_engines(opts),
         QueryAtom::Phantom(is_later_version) => phantom::phantom(is_later_version),
         QueryAtom::BrowserslistConfig => browserslist_config::browserslist_config(opts),
         QueryAtom::Defaults => defaults::defaults(opts),
         QueryAtom::Dead => dead::dead(opts),
+        QueryAtom::Platform(browser_type) => platform::platform(browser_type, opts),
+        QueryAtom::Engine(engine) => engine::engine(engine, opts),
+        QueryAtom::EngineVersion(engine, version) => {
+            engine_version::engine_version(engine, version, opts)
+        }
         QueryAtom::Extends(pkg) => extends::extends(pkg, opts),
         QueryAtom::Unknown(query) => Err(Error::UnknownQuery(query.into())),
     }
@@ -0,0 +1,34 @@
+use super::{node_accurate::node_accurate, QueryResult};
+use crate::{data, error::Error, opts::Opts};
+
+pub(super) fn node_codename(codename: &str, opts: &Opts) -> QueryResult {
+    match data::node_release(codename).filter(|release| release.codename().is_some()) {
+        Some(release) => node_accurate(release.version(), opts),
+        None if opts.ignore_unknown_versions => Ok(vec![]),
+        None => Err(Error::UnknownNodejsVersion(codename.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{resolve, test::should_failed};
+    use test_case::test_case;
+
+    #[test_case("node hydrogen", "node 18"; "basic")]
+    #[test_case("Node Hydrogen", "node 18"; "case insensitive")]
+    fn valid(query: &str, expected: &str) {
+        assert_eq!(
+            resolve([query], &Opts::default()).unwrap(),
+            resolve([expected], &Opts::default()).unwrap()
+        );
+    }
+
+    #[test_case(
+        "node argonaut", Error::UnknownNodejsVersion(String::from("argonaut"));
+        "unknown codename"
+    )]
+    fn invalid(query: &str, error: Error) {
+        assert_eq!(should_failed(query, &Opts::default()), error);
+    }
+}
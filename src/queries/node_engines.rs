@@ -0,0 +1,94 @@
+use super::QueryResult;
+use crate::opts::Opts;
+
+#[cfg(target_arch = "wasm32")]
+pub(super) fn node_engines(_: &Opts) -> QueryResult {
+    Err(crate::error::Error::UnsupportedNodeEngines)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) fn node_engines(opts: &Opts) -> QueryResult {
+    use super::Distrib;
+    use crate::{config, error::Error, semver::VersionReq};
+    use browserslist_data::node;
+
+    let range = config::find_node_engines(opts)?;
+    let req = VersionReq::parse(&range).ok_or(Error::UnknownNodejsVersion(range))?;
+    let distribs = node::versions()
+        .iter()
+        .filter(|version| version.parse().is_ok_and(|version| req.matches(&version)))
+        .map(|version| Distrib::new("node", *version))
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::Error, resolve};
+    use std::{env::temp_dir, fs};
+    use test_case::test_case;
+
+    #[test_case(">=18.17 <23", "node >= 18.17 and node < 23"; "range")]
+    #[test_case("^18.17.0 || 20.x", "node >= 18.17 and node < 19, node >= 20 and node < 21"; "caret or x range")]
+    #[test_case("~20.10", "node >= 20.10 and node < 20.11"; "tilde")]
+    fn valid(engines: &str, expected: &str) {
+        // each case needs its own directory since tests run in parallel
+        let case = engines
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        let dir = temp_dir().join(format!("browserslist-test-node-engines-{case}"));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(
+            dir.join("package.json"),
+            format!(r#"{{"engines":{{"node":"{engines}"}}}}"#),
+        )
+        .unwrap();
+
+        let opts = Opts {
+            path: Some(dir.join("nested").to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        let distribs = resolve(["Node  Engines"], &opts).unwrap();
+        assert!(!distribs.is_empty());
+        assert_eq!(distribs, resolve([expected], &Opts::default()).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_engines() {
+        let dir = temp_dir().join("browserslist-test-node-engines-missing");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("package.json"), "{}").unwrap();
+
+        let opts = Opts {
+            path: Some(dir.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve(["node engines"], &opts).unwrap_err(),
+            Error::MissingEnginesInPkg(format!("{}", dir.join("package.json").display()))
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_package_json() {
+        let dir = temp_dir().join("browserslist-test-node-engines-no-package-json");
+        fs::create_dir_all(&dir).unwrap();
+
+        let opts = Opts {
+            path: Some(dir.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve(["node engines"], &opts).unwrap_err(),
+            Error::MissingPkg(format!("{}", dir.display()))
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
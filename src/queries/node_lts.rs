@@ -0,0 +1,29 @@
+use super::{Distrib, QueryResult};
+use crate::data;
+use chrono::Local;
+
+pub(super) fn node_lts() -> QueryResult {
+    let now = Local::now().naive_local().date();
+    let distribs = data::node_releases()
+        .filter(|release| release.is_lts_at(now))
+        .filter_map(|release| release.latest_version())
+        .map(|version| Distrib::new("node", version))
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{opts::Opts, resolve};
+    use test_case::test_case;
+
+    #[test_case("node lts"; "basic")]
+    #[test_case("Node LTS"; "case insensitive")]
+    #[test_case("node   lts"; "more spaces")]
+    fn valid(query: &str) {
+        assert!(resolve([query], &Opts::default())
+            .unwrap()
+            .iter()
+            .all(|distrib| distrib.name() == "node"));
+    }
+}
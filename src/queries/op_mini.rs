@@ -17,4 +17,13 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::default(), None);
     }
+
+    #[test_case("Opera Mini all"; "display name")]
+    #[test_case("opera mini    all"; "display name case insensitive")]
+    fn display_name(query: &str) {
+        assert_eq!(
+            crate::resolve([query], &Opts::default()).unwrap(),
+            crate::resolve(["op_mini all"], &Opts::default()).unwrap()
+        );
+    }
 }
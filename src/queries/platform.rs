@@ -0,0 +1,35 @@
+use super::{Distrib, QueryResult};
+use crate::{data::BrowserType, opts::Opts};
+use browserslist_data::caniuse::{get_browser_meta, iter_browser_stat};
+
+pub(super) fn platform(browser_type: BrowserType, opts: &Opts) -> QueryResult {
+    let distribs = iter_browser_stat(opts.mobile_to_desktop)
+        .filter(|(name, _)| {
+            get_browser_meta(name).is_some_and(|meta| meta.browser_type == browser_type)
+        })
+        .flat_map(|(name, version_list)| {
+            version_list
+                .iter()
+                .filter(|version| version.released)
+                .map(move |version| Distrib::new(name, version.version()))
+        })
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{data::BrowserType, opts::Opts, resolve};
+    use test_case::test_case;
+
+    #[test_case("last 2 versions and mobile", BrowserType::Mobile; "mobile")]
+    #[test_case("> 0.1% and Desktop", BrowserType::Desktop; "desktop")]
+    #[test_case("last 1 version and not mobile", BrowserType::Desktop; "not mobile")]
+    fn valid(query: &str, browser_type: BrowserType) {
+        let distribs = resolve([query], &Opts::default()).unwrap();
+        assert!(!distribs.is_empty());
+        assert!(distribs
+            .iter()
+            .all(|distrib| distrib.browser_type() == Some(browser_type)));
+    }
+}
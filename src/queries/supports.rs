@@ -1,13 +1,27 @@
 use super::{Distrib, QueryResult};
-use crate::{error::Error, parser::SupportKind, Opts};
-use browserslist_data::caniuse::{features::get_feature_stat, get_browser_stat, to_desktop_name};
+use crate::{
+    error::Error,
+    features::{desktop_fallback, get_support_flags, SupportFlags},
+    parser::SupportKind,
+    Opts,
+};
+use browserslist_data::{
+    caniuse::{features::get_feature_stat, get_browser_stat},
+    node,
+};
 
-const Y: u8 = 1;
-const A: u8 = 2;
-
-pub(super) fn supports(name: &str, kind: Option<SupportKind>, opts: &Opts) -> QueryResult {
+pub(super) fn supports(
+    name: &str,
+    kind: Option<SupportKind>,
+    without_prefix: bool,
+    opts: &Opts,
+) -> QueryResult {
     let include_partial = matches!(kind, Some(SupportKind::Partially) | None);
 
+    if name.starts_with("mdn:") {
+        return mdn_supports(name, include_partial, without_prefix, opts);
+    }
+
     if let Some(feature) = get_feature_stat(name) {
         let distribs = feature
             .iter()
@@ -16,34 +30,39 @@ pub(super) fn supports(name: &str, kind: Option<SupportKind>, opts: &Opts) -> Qu
                     .map(|(name, stat)| (name, stat, versions))
             })
             .flat_map(|(name, browser_stat, versions)| {
-                let desktop_name = opts
-                    .mobile_to_desktop
-                    .then_some(to_desktop_name(name))
-                    .flatten();
-                let check_desktop = desktop_name.is_some()
-                    && browser_stat
-                        .iter()
-                        .filter(|version| version.released)
-                        .filter_map(|latest_version| versions.get(latest_version.version()))
-                        .next_back()
-                        .is_some_and(|flags| is_supported(flags, include_partial));
+                let desktop_name =
+                    desktop_fallback(name, versions, include_partial, without_prefix, opts);
                 browser_stat
                     .iter()
                     .filter_map(move |version| {
-                        versions
-                            .get(version.version())
-                            .or_else(|| match desktop_name {
-                                Some(desktop_name) if check_desktop => feature
-                                    .get(desktop_name)
-                                    .and_then(|versions| versions.get(version.version())),
-                                _ => None,
+                        SupportFlags::from_version_list(versions, version.version())
+                            .or_else(|| {
+                                desktop_name
+                                    .and_then(|desktop_name| feature.get(desktop_name))
+                                    .and_then(|versions| {
+                                        SupportFlags::from_version_list(versions, version.version())
+                                    })
                             })
                             .and_then(|flags| {
-                                is_supported(flags, include_partial).then_some(version)
+                                flags
+                                    .matches(include_partial, without_prefix, opts)
+                                    .then_some(version)
                             })
                     })
                     .map(move |version| Distrib::new(name, version.version()))
             })
+            .chain(
+                // Node.js is checked with Chrome which ships the same V8 version
+                node::versions()
+                    .iter()
+                    .filter(|_| opts.node_in_supports)
+                    .map(|version| Distrib::new("node", *version))
+                    .filter(|distrib| {
+                        get_support_flags(&feature, distrib, opts).is_some_and(|flags| {
+                            flags.matches(include_partial, without_prefix, opts)
+                        })
+                    }),
+            )
             .collect();
         Ok(distribs)
     } else {
@@ -51,8 +70,45 @@ pub(super) fn supports(name: &str, kind: Option<SupportKind>, opts: &Opts) -> Qu
     }
 }
 
-fn is_supported(flags: u8, include_partial: bool) -> bool {
-    flags & Y != 0 || include_partial && flags & A != 0
+#[cfg(feature = "mdn")]
+fn mdn_supports(
+    name: &str,
+    include_partial: bool,
+    without_prefix: bool,
+    opts: &Opts,
+) -> QueryResult {
+    use crate::features::mdn::mdn_support_flags;
+    use browserslist_data::mdn::get_mdn_feature;
+
+    let feature = name
+        .strip_prefix("mdn:")
+        .and_then(get_mdn_feature)
+        .ok_or_else(|| Error::UnknownBrowserFeature(name.to_string()))?;
+    let distribs = feature
+        .iter()
+        .filter_map(|(name, statements)| {
+            get_browser_stat(name, opts.mobile_to_desktop)
+                .map(|(name, stat)| (name, stat, statements))
+        })
+        .flat_map(|(name, browser_stat, statements)| {
+            browser_stat
+                .iter()
+                .filter(move |version| {
+                    mdn_support_flags(statements, version.version()).matches(
+                        include_partial,
+                        without_prefix,
+                        opts,
+                    )
+                })
+                .map(move |version| Distrib::new(name, version.version()))
+        })
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(not(feature = "mdn"))]
+fn mdn_supports(name: &str, _: bool, _: bool, _: &Opts) -> QueryResult {
+    Err(Error::MdnFeatureDisabled(name.to_string()))
 }
 
 #[cfg(test)]
@@ -86,6 +142,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mobile_to_desktop_agrees_with_support_flags() {
+        let opts = Opts {
+            mobile_to_desktop: true,
+            ..Default::default()
+        };
+        let supported = crate::resolve(["supports font-smooth"], &opts).unwrap();
+        let distribs = crate::resolve(["and_chr > 90"], &opts).unwrap();
+        assert!(!distribs.is_empty());
+        assert!(distribs.iter().all(|distrib| {
+            let flags = crate::features::support_flags("font-smooth", distrib, &opts).unwrap();
+            flags.is_some_and(|flags| flags.matches(true, false, &opts))
+                == supported.contains(distrib)
+        }));
+    }
+
+    #[test_case("supports css-grid without prefix"; "css grid")]
+    #[test_case("partially supports  css-grid  without   prefix"; "partially")]
+    fn without_prefix(query: &str) {
+        let opts = Opts::default();
+        let distribs = crate::resolve([query], &opts).unwrap();
+        let feature = query.split_whitespace().nth_back(2).unwrap();
+        assert!(!distribs.is_empty());
+        assert!(distribs.iter().all(|distrib| {
+            !crate::features::support_flags(feature, distrib, &opts)
+                .unwrap()
+                .unwrap()
+                .needs_prefix()
+        }));
+    }
+
+    #[cfg(feature = "mdn")]
+    #[test_case("supports mdn:api.fetch", &["firefox 60"], &["ie 11", "firefox 3.5"]; "version added")]
+    #[test_case("fully supports mdn:api.Navigator.share", &["safari 15.4"], &["chrome 100"]; "partial")]
+    #[test_case("supports mdn:css.properties.user-select without prefix", &["firefox 93"], &["safari 15.4"]; "prefix")]
+    fn mdn(query: &str, included: &[&str], excluded: &[&str]) {
+        let distribs = crate::resolve([query], &Opts::default())
+            .unwrap()
+            .into_iter()
+            .map(|distrib| distrib.to_string())
+            .collect::<Vec<_>>();
+        assert!(included
+            .iter()
+            .all(|d| distribs.iter().any(|distrib| distrib == d)));
+        assert!(excluded
+            .iter()
+            .all(|d| distribs.iter().all(|distrib| distrib != d)));
+    }
+
+    #[test_case("supports arrow-functions", &["node 16.0.0", "node 5.1.0"], &["node 0.10.0"]; "basic")]
+    #[test_case("fully supports es6-module", &["node 22.11.0"], &["node 0.10.0"]; "fully")]
+    fn node(query: &str, included: &[&str], excluded: &[&str]) {
+        let opts = Opts {
+            node_in_supports: true,
+            ..Default::default()
+        };
+        let distribs = crate::resolve([query], &opts)
+            .unwrap()
+            .into_iter()
+            .map(|distrib| distrib.to_string())
+            .collect::<Vec<_>>();
+        assert!(included
+            .iter()
+            .all(|d| distribs.iter().any(|distrib| distrib == d)));
+        assert!(excluded
+            .iter()
+            .all(|d| distribs.iter().all(|distrib| distrib != d)));
+    }
+
+    #[test_case("supports arrow-functions", false; "disabled")]
+    #[test_case("supports css-grid", true; "not javascript")]
+    fn without_node(query: &str, node_in_supports: bool) {
+        let opts = Opts {
+            node_in_supports,
+            ..Default::default()
+        };
+        let distribs = crate::resolve([query], &opts).unwrap();
+        assert!(!distribs.is_empty());
+        assert!(distribs.iter().all(|distrib| distrib.name() != "node"));
+    }
+
     #[test]
     fn invalid() {
         assert_eq!(
@@ -93,4 +230,17 @@ mod tests {
             Error::UnknownBrowserFeature(String::from("xxxyyyzzz"))
         );
     }
+
+    #[test]
+    fn invalid_mdn() {
+        let error = if cfg!(feature = "mdn") {
+            Error::UnknownBrowserFeature(String::from("mdn:api.xxxyyyzzz"))
+        } else {
+            Error::MdnFeatureDisabled(String::from("mdn:api.xxxyyyzzz"))
+        };
+        assert_eq!(
+            should_failed("supports mdn:api.xxxyyyzzz", &Opts::default()),
+            error
+        );
+    }
 }
@@ -1,13 +1,45 @@
-use std::{cmp::Ordering, num::ParseIntError, str::FromStr};
+use std::{cmp::Ordering, fmt, num::ParseIntError, str::FromStr};
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Default, Debug, Clone)]
-pub(crate) struct Version(u32, u32, u32);
+#[derive(PartialEq, Eq, PartialOrd, Ord, Default, Debug, Clone, Copy, Hash)]
+/// Numeric version with major, minor and patch components.
+///
+/// Missing components are treated as `0`,
+/// and for version range like `4.4.3-4.4.4`, only the lower bound is kept.
+///
+/// ```
+/// use browserslist::Version;
+///
+/// let version: Version = "4.4.3-4.4.4".parse().unwrap();
+/// assert_eq!(version.major(), 4);
+/// assert_eq!(version.minor(), 4);
+/// assert_eq!(version.patch(), 3);
+/// ```
+pub struct Version(u32, u32, u32);
 
 impl Version {
     #[inline]
-    pub(crate) fn major(&self) -> u32 {
+    /// Return major version.
+    pub fn major(&self) -> u32 {
         self.0
     }
+
+    #[inline]
+    /// Return minor version.
+    pub fn minor(&self) -> u32 {
+        self.1
+    }
+
+    #[inline]
+    /// Return patch version.
+    pub fn patch(&self) -> u32 {
+        self.2
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
 }
 
 impl FromStr for Version {
@@ -33,6 +65,170 @@ impl FromStr for Version {
     }
 }
 
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+/// Parsed version of a [`Distrib`](crate::Distrib).
+///
+/// Non-numeric versions are ordered before numeric versions,
+/// which is consistent with the order of the result of [`resolve`](crate::resolve).
+pub enum DistribVersion {
+    /// `all` version of Opera Mini.
+    All,
+    /// Safari Technology Preview (`TP`).
+    TechnologyPreview,
+    /// Numeric version.
+    Numeric(Version),
+}
+
+impl FromStr for DistribVersion {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            Ok(Self::All)
+        } else if s.eq_ignore_ascii_case("tp") {
+            Ok(Self::TechnologyPreview)
+        } else {
+            s.parse().map(Self::Numeric)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Version range in npm semver syntax, such as `>=18.17 <23`, `^16.0.0 || ~18.2` and `20.x`.
+///
+/// Prerelease tags and build metadata are ignored.
+pub(crate) struct VersionReq(Vec<Vec<(Ordering, bool, Version)>>);
+
+impl VersionReq {
+    /// Parse an npm semver range, or return `None` if it's malformed.
+    pub(crate) fn parse(input: &str) -> Option<Self> {
+        input
+            .split("||")
+            .map(|range| {
+                let range = normalize_operators(range.trim());
+                let tokens = range.split_whitespace().collect::<Vec<_>>();
+                match tokens.as_slice() {
+                    [from, "-", to] => {
+                        let mut comparators = parse_comparator(&format!(">={from}"))?;
+                        comparators.extend(parse_comparator(&format!("<={to}"))?);
+                        Some(comparators)
+                    }
+                    tokens => tokens.iter().try_fold(vec![], |mut comparators, token| {
+                        comparators.extend(parse_comparator(token)?);
+                        Some(comparators)
+                    }),
+                }
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Self)
+    }
+
+    /// Check if the given version satisfies this range.
+    pub(crate) fn matches(&self, version: &Version) -> bool {
+        self.0.iter().any(|comparators| {
+            comparators.iter().all(|(ordering, or_equal, bound)| {
+                let actual = version.cmp(bound);
+                actual == *ordering || *or_equal && actual == Ordering::Equal
+            })
+        })
+    }
+}
+
+/// Remove spaces between operators and versions, like `>= 1.2` to `>=1.2`.
+fn normalize_operators(range: &str) -> String {
+    let mut normalized = String::with_capacity(range.len());
+    let mut chars = range.chars().peekable();
+    while let Some(c) = chars.next() {
+        normalized.push(c);
+        if matches!(c, '<' | '>' | '=' | '~' | '^') {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        }
+    }
+    normalized
+}
+
+/// Parse partial version like `1`, `1.2.x` and `*`, with optional `v` prefix.
+/// Missing or wildcard components are returned as `None`.
+fn parse_partial(input: &str) -> Option<[Option<u32>; 3]> {
+    let input = input.trim_start_matches(['v', '=']);
+    // ignore prerelease tags and build metadata
+    let input = input.split(['-', '+']).next().unwrap_or_default();
+    let mut partial = [None; 3];
+    if input.is_empty() {
+        return Some(partial);
+    }
+    let mut segments = input.split('.');
+    for component in &mut partial {
+        match segments.next() {
+            Some("x" | "X" | "*") | None => break,
+            Some(segment) => *component = Some(segment.parse().ok()?),
+        }
+    }
+    segments.next().is_none().then_some(partial)
+}
+
+/// Parse a comparator into primitive comparators of
+/// ordering relative to the bound, whether to also accept equality, and the bound.
+fn parse_comparator(input: &str) -> Option<Vec<(Ordering, bool, Version)>> {
+    use Ordering::{Equal, Greater, Less};
+
+    let operator_len = input
+        .find(|c: char| !matches!(c, '<' | '>' | '=' | '~' | '^'))
+        .unwrap_or(input.len());
+    let (operator, partial) = input.split_at(operator_len);
+    let partial = parse_partial(partial)?;
+    let lower = Version(
+        partial[0].unwrap_or_default(),
+        partial[1].unwrap_or_default(),
+        partial[2].unwrap_or_default(),
+    );
+    // the smallest version which is greater than all versions matching the partial version
+    let next = match partial {
+        [Some(major), None, _] => Some(Version(major + 1, 0, 0)),
+        [Some(major), Some(minor), None] => Some(Version(major, minor + 1, 0)),
+        _ => None,
+    };
+
+    let comparators = match (operator, partial) {
+        (_, [None, ..]) => match operator {
+            "<" | ">" => vec![(Less, false, Version(0, 0, 0))],
+            _ => vec![],
+        },
+        ("" | "=", _) => match next {
+            Some(next) => vec![(Greater, true, lower), (Less, false, next)],
+            None => vec![(Equal, false, lower)],
+        },
+        (">", _) => match next {
+            Some(next) => vec![(Greater, true, next)],
+            None => vec![(Greater, false, lower)],
+        },
+        (">=", _) => vec![(Greater, true, lower)],
+        ("<", _) => vec![(Less, false, lower)],
+        ("<=", _) => match next {
+            Some(next) => vec![(Less, false, next)],
+            None => vec![(Less, true, lower)],
+        },
+        ("~" | "~>", _) => vec![
+            (Greater, true, lower),
+            (
+                Less,
+                false,
+                next.unwrap_or(Version(lower.0, lower.1 + 1, 0)),
+            ),
+        ],
+        ("^", [Some(major), minor, patch]) => {
+            let upper = match (major, minor, patch) {
+                (0, Some(0), Some(patch)) => Version(0, 0, patch + 1),
+                (0, Some(minor), _) => Version(0, minor + 1, 0),
+                (major, ..) => Version(major + 1, 0, 0),
+            };
+            vec![(Greater, true, lower), (Less, false, upper)]
+        }
+        _ => return None,
+    };
+    Some(comparators)
+}
+
 pub(crate) fn compare(a: &str, b: &str) -> Ordering {
     a.parse::<Version>()
         .unwrap_or_default()
@@ -72,4 +268,60 @@ mod tests {
 
         assert!("tp".parse::<Version>().is_err());
     }
+
+    #[test]
+    fn version_req() {
+        let matches = |range: &str, version: &str| {
+            VersionReq::parse(range)
+                .unwrap()
+                .matches(&version.parse().unwrap())
+        };
+
+        assert!(matches(">=18.17 <23", "18.17.0"));
+        assert!(matches(">= 18.17 < 23", "22.11.0"));
+        assert!(!matches(">=18.17 <23", "18.16.1"));
+        assert!(!matches(">=18.17 <23", "23.0.0"));
+
+        assert!(matches("^16.2.0", "16.20.2"));
+        assert!(!matches("^16.2.0", "16.1.0"));
+        assert!(!matches("^16.2.0", "17.0.0"));
+        assert!(matches("^0.10.1", "0.10.48"));
+        assert!(!matches("^0.10.1", "0.11.0"));
+
+        assert!(matches("~18.2", "18.2.1"));
+        assert!(!matches("~18.2", "18.3.0"));
+        assert!(matches("~18.2.1", "18.2.5"));
+        assert!(!matches("~18.2.1", "18.2.0"));
+
+        assert!(matches("18.x || 20", "18.17.0"));
+        assert!(matches("18.x || 20", "20.10.0"));
+        assert!(!matches("18.x || 20", "22.0.0"));
+        assert!(matches("v20.10.0", "20.10.0"));
+        assert!(!matches("20.10.0", "20.10.1"));
+
+        assert!(matches(">16", "17.0.0"));
+        assert!(!matches(">16", "16.20.0"));
+        assert!(matches("<=16", "16.20.0"));
+        assert!(matches("16 - 18.1", "18.1.9"));
+        assert!(!matches("16 - 18.1", "18.2.0"));
+        assert!(matches("*", "0.10.0"));
+        assert!(matches("", "23.1.0"));
+        assert!(matches(">=20.0.0-rc.1", "20.0.0"));
+
+        assert!(VersionReq::parse(">=foo").is_none());
+        assert!(VersionReq::parse("1.2.3.4").is_none());
+    }
+
+    #[test]
+    fn parse_distrib_version() {
+        assert_eq!(Ok(DistribVersion::All), "all".parse());
+        assert_eq!(Ok(DistribVersion::TechnologyPreview), "TP".parse());
+        assert_eq!(
+            Ok(DistribVersion::Numeric(Version(4, 4, 3))),
+            "4.4.3-4.4.4".parse()
+        );
+        assert!("x".parse::<DistribVersion>().is_err());
+
+        assert!(DistribVersion::TechnologyPreview < DistribVersion::Numeric(Version(0, 0, 0)));
+    }
 }
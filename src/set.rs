@@ -0,0 +1,165 @@
+use crate::{queries::Distrib, semver::DistribVersion};
+use itertools::Itertools;
+use std::collections::{btree_set, BTreeSet};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// A set of [`Distrib`]s, which is iterated in the same order as the result of
+/// [`resolve`](crate::resolve).
+///
+/// ```
+/// use browserslist::{resolve_set, Opts};
+///
+/// let ie = resolve_set(["ie >= 9"], &Opts::default()).unwrap();
+/// let old = resolve_set(["ie <= 10"], &Opts::default()).unwrap();
+///
+/// assert_eq!(
+///     ie.intersection(&old).iter().map(|d| d.to_string()).collect::<Vec<_>>(),
+///     vec!["ie 10", "ie 9"]
+/// );
+/// assert_eq!(
+///     ie.difference(&old).iter().map(|d| d.to_string()).collect::<Vec<_>>(),
+///     vec!["ie 11"]
+/// );
+/// ```
+pub struct DistribSet(BTreeSet<Distrib>);
+
+impl DistribSet {
+    #[inline]
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    /// Return the number of distribs in this set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    /// Return `true` if this set contains no distribs.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    /// Return `true` if this set contains the given distrib.
+    pub fn contains(&self, distrib: &Distrib) -> bool {
+        self.0.contains(distrib)
+    }
+
+    #[inline]
+    /// Add a distrib to this set.
+    /// Return whether the distrib is newly inserted.
+    pub fn insert(&mut self, distrib: Distrib) -> bool {
+        self.0.insert(distrib)
+    }
+
+    #[inline]
+    /// Remove a distrib from this set.
+    /// Return whether the distrib was present.
+    pub fn remove(&mut self, distrib: &Distrib) -> bool {
+        self.0.remove(distrib)
+    }
+
+    #[inline]
+    /// Iterate over distribs by name alphabetically,
+    /// then by version from newest to oldest.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Distrib> + DoubleEndedIterator {
+        self.0.iter()
+    }
+
+    /// Return distribs which are in this set or in the other set.
+    pub fn union(&self, other: &DistribSet) -> DistribSet {
+        Self(self.0.union(&other.0).cloned().collect())
+    }
+
+    /// Return distribs which are in both this set and the other set.
+    pub fn intersection(&self, other: &DistribSet) -> DistribSet {
+        Self(self.0.intersection(&other.0).cloned().collect())
+    }
+
+    /// Return distribs which are in this set but not in the other set.
+    pub fn difference(&self, other: &DistribSet) -> DistribSet {
+        Self(self.0.difference(&other.0).cloned().collect())
+    }
+
+    #[inline]
+    /// Retain only the distribs specified by the predicate.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&Distrib) -> bool,
+    {
+        self.0.retain(f)
+    }
+
+    /// Iterate over the oldest version of each browser, by name alphabetically.
+    ///
+    /// Non-numeric versions like `safari TP` are only picked
+    /// when there are no numeric versions of that browser.
+    ///
+    /// ```
+    /// use browserslist::{resolve_set, Opts};
+    ///
+    /// let distribs = resolve_set(["ie >= 9", "firefox > 90", "safari tp"], &Opts::default()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     distribs.min_version_per_browser().map(|d| d.to_string()).collect::<Vec<_>>(),
+    ///     vec!["firefox 91", "ie 9", "safari TP"]
+    /// );
+    /// ```
+    pub fn min_version_per_browser(&self) -> impl Iterator<Item = &Distrib> {
+        self.0
+            .iter()
+            .chunk_by(|distrib| distrib.name())
+            .into_iter()
+            .filter_map(|(_, versions)| {
+                versions.fold(None, |min: Option<&Distrib>, distrib| match min {
+                    Some(min)
+                        if !matches!(distrib.parsed_version(), DistribVersion::Numeric(_)) =>
+                    {
+                        Some(min)
+                    }
+                    _ => Some(distrib),
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl FromIterator<Distrib> for DistribSet {
+    fn from_iter<T: IntoIterator<Item = Distrib>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Distrib> for DistribSet {
+    fn extend<T: IntoIterator<Item = Distrib>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for DistribSet {
+    type Item = Distrib;
+    type IntoIter = btree_set::IntoIter<Distrib>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a DistribSet {
+    type Item = &'a Distrib;
+    type IntoIter = btree_set::Iter<'a, Distrib>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl From<DistribSet> for Vec<Distrib> {
+    fn from(set: DistribSet) -> Self {
+        set.0.into_iter().collect()
+    }
+}
@@ -0,0 +1,314 @@
+//! Convert resolved distribs into target formats of other tools.
+//!
+//! All converters only pick the minimum version of each browser,
+//! and non-numeric versions like `safari TP` and `op_mini all` are ignored.
+//!
+//! ```
+//! use browserslist::{resolve, targets, Opts};
+//!
+//! let distribs = resolve(["chrome >= 100", "ios_saf >= 15.4"], &Opts::default()).unwrap();
+//!
+//! assert_eq!(targets::esbuild(&distribs), vec!["chrome100", "ios15.4"]);
+//! ```
+
+use crate::{data::Engine, queries::Distrib, semver::Version, DistribVersion};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// Collect the minimum numeric version of each browser.
+///
+/// Browser names can be renamed or dropped by the `rename` function,
+/// which receives the original name and its version.
+/// When several browsers are renamed to a same name,
+/// the minimum version among them is picked.
+///
+/// ```
+/// use browserslist::{resolve, targets, Opts};
+///
+/// let distribs = resolve(["ie >= 9", "firefox > 90"], &Opts::default()).unwrap();
+/// let versions = targets::min_versions(&distribs, |name, _| Some(name));
+///
+/// assert_eq!(versions["ie"].major(), 9);
+/// assert_eq!(versions["firefox"].major(), 91);
+/// ```
+pub fn min_versions<'a, I, F>(distribs: I, mut rename: F) -> BTreeMap<&'a str, Version>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+    F: FnMut(&'a str, &Version) -> Option<&'a str>,
+{
+    distribs
+        .into_iter()
+        .filter_map(|distrib| match distrib.parsed_version() {
+            DistribVersion::Numeric(version) => {
+                rename(distrib.name(), &version).map(|name| (name, version))
+            }
+            _ => None,
+        })
+        .fold(BTreeMap::new(), |mut versions, (name, version)| {
+            versions
+                .entry(name)
+                .and_modify(|min: &mut Version| *min = (*min).min(version))
+                .or_insert(version);
+            versions
+        })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// Targets of [Lightning CSS](https://lightningcss.dev/).
+///
+/// Each version is packed as `major << 16 | minor << 8 | patch`.
+/// It can be serialized and then deserialized as `lightningcss::targets::Browsers`.
+///
+/// ```
+/// use browserslist::{resolve, targets, Opts};
+///
+/// let distribs = resolve(["safari >= 15.4", "and_chr > 0"], &Opts::default()).unwrap();
+/// let browsers = targets::lightningcss(&distribs);
+///
+/// assert_eq!(browsers.safari, Some(15 << 16 | 4 << 8));
+/// assert!(browsers.chrome.is_some());
+/// assert_eq!(browsers.ie, None);
+/// ```
+pub struct LightningcssTargets {
+    /// Android Browser.
+    pub android: Option<u32>,
+    /// Chrome, including Chrome for Android.
+    pub chrome: Option<u32>,
+    /// Edge.
+    pub edge: Option<u32>,
+    /// Firefox, including Firefox for Android.
+    pub firefox: Option<u32>,
+    /// Internet Explorer.
+    pub ie: Option<u32>,
+    /// Safari on iOS.
+    pub ios_saf: Option<u32>,
+    /// Opera, including Opera Mobile.
+    pub opera: Option<u32>,
+    /// Safari.
+    pub safari: Option<u32>,
+    /// Samsung Internet.
+    pub samsung: Option<u32>,
+}
+
+/// Convert distribs into targets of [Lightning CSS](https://lightningcss.dev/).
+pub fn lightningcss<'a, I>(distribs: I) -> LightningcssTargets
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    let distribs = electron_to_chrome(distribs);
+    let versions = min_versions(
+        distribs.iter().map(|distrib| &**distrib),
+        |name, _| match name {
+            "and_chr" => Some("chrome"),
+            "and_ff" => Some("firefox"),
+            "op_mob" => Some("opera"),
+            _ => Some(name),
+        },
+    );
+    let get = |name: &str| {
+        versions.get(name).map(|version| {
+            (version.major() & 0xff) << 16
+                | (version.minor() & 0xff) << 8
+                | (version.patch() & 0xff)
+        })
+    };
+
+    LightningcssTargets {
+        android: get("android"),
+        chrome: get("chrome"),
+        edge: get("edge"),
+        firefox: get("firefox"),
+        ie: get("ie"),
+        ios_saf: get("ios_saf"),
+        opera: get("opera"),
+        safari: get("safari"),
+        samsung: get("samsung"),
+    }
+}
+
+/// Convert distribs into targets of [esbuild](https://esbuild.github.io/),
+/// such as `chrome100` and `safari15.4`.
+///
+/// The result can be joined with `,` and passed to `--target`.
+/// Android Browser and Electron which are based on Chromium are treated as Chrome,
+/// and browsers which esbuild doesn't recognize are dropped.
+pub fn esbuild<'a, I>(distribs: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    let distribs = electron_to_chrome(distribs);
+    min_versions(
+        distribs.iter().map(|distrib| &**distrib),
+        |name, version| match name {
+            "chrome" | "and_chr" => Some("chrome"),
+            "android" if version.major() >= 37 => Some("chrome"),
+            "firefox" | "and_ff" => Some("firefox"),
+            "ios_saf" => Some("ios"),
+            "edge" | "ie" | "node" | "opera" | "safari" => Some(name),
+            _ => None,
+        },
+    )
+    .into_iter()
+    .map(|(name, version)| format!("{name}{}", format_version(&version)))
+    .collect()
+}
+
+/// Convert distribs into targets of [SWC](https://swc.rs/),
+/// which can be deserialized as `swc_ecma_preset_env::Versions`.
+///
+/// ```
+/// use browserslist::{resolve, targets, Opts};
+///
+/// let distribs = resolve(["ios_saf >= 15.4", "op_mob > 0"], &Opts::default()).unwrap();
+/// let versions = targets::swc(&distribs);
+///
+/// assert_eq!(versions["ios"], "15.4");
+/// assert!(versions.contains_key("opera_mobile"));
+/// ```
+pub fn swc<'a, I>(distribs: I) -> BTreeMap<&'a str, String>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    min_versions(distribs, |name, _| match name {
+        "and_chr" => Some("chrome"),
+        "and_ff" => Some("firefox"),
+        "ios_saf" => Some("ios"),
+        "op_mob" => Some("opera_mobile"),
+        "android" | "chrome" | "edge" | "electron" | "firefox" | "ie" | "node" | "opera"
+        | "safari" | "samsung" => Some(name),
+        _ => None,
+    })
+    .into_iter()
+    .map(|(name, version)| (name, format_version(&version)))
+    .collect()
+}
+
+/// Convert distribs into the `targets` option of
+/// [`@babel/preset-env`](https://babeljs.io/docs/babel-preset-env#targets).
+///
+/// Browsers are renamed as what Babel recognizes,
+/// such as `ios` for `ios_saf` and `opera_mobile` for `op_mob`,
+/// and the lowest version of each browser is kept as it is.
+/// Electron queries are resolved as Chrome versions and included as `chrome`,
+/// unless the `keep_electron` option is enabled.
+///
+/// ```
+/// use browserslist::{resolve, targets, Opts};
+///
+/// let distribs = resolve(["chrome >= 100", "ios_saf >= 15.4", "node 18.0"], &Opts::default()).unwrap();
+///
+/// assert_eq!(
+///     serde_json::to_string(&targets::babel(&distribs)).unwrap(),
+///     r#"{"chrome":"100","ios":"15.4","node":"18.0.0"}"#
+/// );
+/// ```
+pub fn babel<'a, I>(distribs: I) -> BTreeMap<&'static str, &'a str>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    distribs
+        .into_iter()
+        .filter_map(|distrib| {
+            let name = match distrib.name() {
+                "and_chr" | "chrome" => "chrome",
+                "and_ff" | "firefox" => "firefox",
+                "ie" | "ie_mob" => "ie",
+                "ios_saf" => "ios",
+                "op_mob" => "opera_mobile",
+                "android" => "android",
+                "edge" => "edge",
+                "electron" => "electron",
+                "node" => "node",
+                "opera" => "opera",
+                "safari" => "safari",
+                "samsung" => "samsung",
+                _ => return None,
+            };
+            match distrib.parsed_version() {
+                DistribVersion::Numeric(version) => {
+                    let raw = distrib.version();
+                    Some((name, version, raw.split_once('-').map_or(raw, |(v, _)| v)))
+                }
+                _ => None,
+            }
+        })
+        .fold(
+            BTreeMap::<_, (Version, _)>::new(),
+            |mut versions, (name, version, raw)| {
+                versions
+                    .entry(name)
+                    .and_modify(|min| {
+                        if version < min.0 {
+                            *min = (version, raw);
+                        }
+                    })
+                    .or_insert((version, raw));
+                versions
+            },
+        )
+        .into_iter()
+        .map(|(name, (_, raw))| (name, raw))
+        .collect()
+}
+
+/// Collapse distribs into the minimum version of each rendering engine,
+/// such as Chromium 115 for `samsung 23` and `chrome 120`.
+///
+/// Distribs whose engine version is unknown are ignored.
+///
+/// ```
+/// use browserslist::{data::Engine, resolve, targets, Opts};
+///
+/// let distribs = resolve(["samsung 23", "chrome 120", "firefox 93"], &Opts::default()).unwrap();
+/// let engines = targets::engines(&distribs);
+///
+/// assert_eq!(engines[&Engine::Blink].major(), 115);
+/// assert_eq!(engines[&Engine::Gecko].major(), 93);
+/// ```
+pub fn engines<'a, I>(distribs: I) -> BTreeMap<Engine, Version>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    distribs
+        .into_iter()
+        .filter_map(|distrib| Some((distrib.engine()?, distrib.engine_version()?)))
+        .fold(BTreeMap::new(), |mut engines, (engine, version)| {
+            engines
+                .entry(engine)
+                .and_modify(|min: &mut Version| *min = (*min).min(version))
+                .or_insert(version);
+            engines
+        })
+}
+
+/// Replace Electron distribs kept by the `keep_electron` option with Chrome distribs,
+/// for tools which don't recognize Electron.
+fn electron_to_chrome<'a, I>(distribs: I) -> Vec<Cow<'a, Distrib>>
+where
+    I: IntoIterator<Item = &'a Distrib>,
+{
+    distribs
+        .into_iter()
+        .map(|distrib| {
+            distrib
+                .electron_to_chrome()
+                .map_or(Cow::Borrowed(distrib), Cow::Owned)
+        })
+        .collect()
+}
+
+/// Format version without trailing zero components, such as `15.4` and `100`.
+pub(crate) fn format_version(version: &Version) -> String {
+    if version.patch() != 0 {
+        format!(
+            "{}.{}.{}",
+            version.major(),
+            version.minor(),
+            version.patch()
+        )
+    } else if version.minor() != 0 {
+        format!("{}.{}", version.major(), version.minor())
+    } else {
+        version.major().to_string()
+    }
+}